@@ -13,6 +13,9 @@
 )]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![doc = include_str!("../README.md")]
+#[cfg(not(any(feature = "rustls-tls", feature = "native-tls")))]
+compile_error!("either the `rustls-tls` or `native-tls` feature must be enabled");
+
 mod stdx;
 
 pub mod platform;
@@ -0,0 +1,35 @@
+//! Module containing a genre taxonomy shared across platforms.
+
+use serde::{Deserialize, Serialize};
+
+/// A genre that has a clean counterpart on every supported platform.
+///
+/// Each platform has its own genres, with different variants and languages. This is the common subset they can be
+/// mapped into, so that, for example, "Fantasy" on one platform compares equal to "Fantasy" on another.
+///
+/// Platform genres without a clean counterpart, like a genre that straddles two of these, map to `None` instead of
+/// being forced into one.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CommonGenre {
+    /// Comedy
+    Comedy,
+    /// Fantasy
+    Fantasy,
+    /// Romance
+    Romance,
+    /// Slice of Life
+    SliceOfLife,
+    /// Action
+    Action,
+    /// Drama
+    Drama,
+    /// Thriller
+    Thriller,
+    /// Historical
+    Historical,
+    /// Sports
+    Sports,
+    /// Heartwarming
+    Heartwarming,
+}
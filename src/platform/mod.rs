@@ -1,3 +1,4 @@
 //! Module representing the platforms that this crate supports.
 
+pub mod genre;
 pub mod webtoons;
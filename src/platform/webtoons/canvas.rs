@@ -57,8 +57,8 @@ pub(super) async fn scrape(
     let mut webtoons = Vec::with_capacity(usize::from(end - start + 1) * 20);
 
     for page in start..end {
-        let response = match client.get_canvas_page(language, page, sort).await {
-            Ok(response) => response,
+        let document = match client.get_canvas_page(language, page, sort).await {
+            Ok(document) => document,
             Err(ClientError::RateLimitExceeded(retry_after)) => {
                 tokio::time::sleep(Duration::from_secs(retry_after)).await;
                 client.get_canvas_page(language, page, sort).await?
@@ -66,8 +66,6 @@ pub(super) async fn scrape(
             Err(err) => return Err(CanvasError::ClientError(err)),
         };
 
-        let document = response.text().await?;
-
         let html = Html::parse_document(&document);
 
         for card in html.select(&selector) {
@@ -0,0 +1,56 @@
+//! Host handling for the image CDNs that webtoons.com serves panels and thumbnails from.
+
+use url::Url;
+
+/// Serves the same images as the other `*-phinf.pstatic.net` hosts, but without checking the `Referer` header.
+const REFERER_FREE_HOST: &str = "swebtoon-phinf.pstatic.net";
+
+/// `Referer` to send when requesting an image from a host that couldn't be rewritten.
+#[cfg(feature = "download")]
+pub(crate) const REFERER: &str = "https://www.webtoons.com";
+
+/// Points a `*-phinf.pstatic.net` url at the host that doesn't need a `Referer` to serve the image.
+///
+/// Any other host is left alone, as the same path doesn't exist there. Returns whether the url was rewritten.
+pub(crate) fn rewrite_host(url: &mut Url) -> bool {
+    let is_phinf = url
+        .host_str()
+        .is_some_and(|host| host.ends_with("-phinf.pstatic.net"));
+
+    if is_phinf {
+        url.set_host(Some(REFERER_FREE_HOST))
+            .expect("`swebtoon-phinf.pstatic.net` should be a valid host");
+    }
+
+    is_phinf
+}
+
+/// Returns if requesting `url` needs the [`REFERER`] header to be sent.
+#[cfg(feature = "download")]
+pub(crate) fn needs_referer(url: &Url) -> bool {
+    url.host_str() != Some(REFERER_FREE_HOST)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_only_rewrite_phinf_hosts() {
+        let mut known =
+            Url::parse("https://webtoon-phinf.pstatic.net/20240101_1/panel.jpg").unwrap();
+        assert!(rewrite_host(&mut known));
+        assert_eq!(
+            "https://swebtoon-phinf.pstatic.net/20240101_1/panel.jpg",
+            known.as_str()
+        );
+
+        let mut unknown = Url::parse("https://cdn.webtoons.com/20240101_1/panel.jpg").unwrap();
+        assert!(!rewrite_host(&mut unknown));
+        assert_eq!(
+            "https://cdn.webtoons.com/20240101_1/panel.jpg",
+            unknown.as_str()
+        );
+    }
+}
@@ -1,8 +1,12 @@
 //! Represents an abstraction for the `https://www.webtoons.com/*/canvas/list?genreTab=ALL&sortOrder=` endpoint.
 
+#[cfg(feature = "cookies")]
+mod cookies;
+pub(super) mod dns;
 pub(super) mod likes;
 pub(super) mod posts;
 pub mod search;
+pub(crate) mod trace;
 
 use super::{
     canvas::{self, Sort},
@@ -12,21 +16,30 @@ use super::{
         WebtoonError,
     },
     meta::Scope,
-    originals::{self},
+    originals::{self, WebtoonListing},
     webtoon::episode::{
-        posts::{Post, Reaction},
-        Episode,
+        posts::{Post, PostSort, Reaction},
+        Episode, PageCache,
     },
     Language, Type, Webtoon,
 };
+#[cfg(feature = "download")]
+use super::{
+    errors::DownloadError,
+    webtoon::episode::{self, Panels},
+};
+use crate::stdx::lru::Lru;
 use anyhow::{anyhow, Context};
 use posts::id::Id;
 use reqwest::Response;
+use scraper::{Html, Selector};
 use search::Item;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::{collections::HashMap, env, ops::RangeBounds, str::FromStr, sync::Arc};
 use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
+use trace::Traced;
 
 static APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
 
@@ -59,7 +72,17 @@ static APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_P
 #[derive(Debug)]
 pub struct ClientBuilder {
     builder: reqwest::ClientBuilder,
+    http: Option<reqwest::Client>,
     session: Option<Arc<str>>,
+    /// Extra cookies from [`ClientBuilder::cookies`] as name and value, checked and joined once the `Client` is built.
+    cookies: Vec<(String, String)>,
+    accept_language: Option<Arc<str>>,
+    #[cfg(feature = "cookies")]
+    cookie_store: Option<std::path::PathBuf>,
+    page_cache: Option<usize>,
+    handle_cache: bool,
+    listing_cache: bool,
+    cancellation: Option<CancellationToken>,
 }
 
 impl Default for ClientBuilder {
@@ -74,6 +97,8 @@ impl ClientBuilder {
     /// This includes a default user agent (`webtoon/VERSION`).
     /// This is the starting point for configuring a `Client`.
     ///
+    /// The TLS backend is picked from the enabled features: `native-tls` if enabled, otherwise `rustls-tls`, which is the default.
+    ///
     /// ### Example
     ///
     /// ```rust
@@ -84,13 +109,29 @@ impl ClientBuilder {
     pub fn new() -> Self {
         let builder = reqwest::Client::builder()
             .user_agent(APP_USER_AGENT)
-            .use_rustls_tls()
             .https_only(true)
-            .brotli(true);
+            .brotli(true)
+            .dns_resolver(Arc::new(dns::Resolver));
+
+        // `native-tls` is opt-in, so if it was enabled it should win over the default `rustls-tls`.
+        #[cfg(feature = "native-tls")]
+        let builder = builder.use_native_tls();
+
+        #[cfg(all(feature = "rustls-tls", not(feature = "native-tls")))]
+        let builder = builder.use_rustls_tls();
 
         Self {
             builder,
+            http: None,
             session: None,
+            cookies: Vec::new(),
+            accept_language: None,
+            #[cfg(feature = "cookies")]
+            cookie_store: None,
+            page_cache: None,
+            handle_cache: false,
+            listing_cache: false,
+            cancellation: None,
         }
     }
 
@@ -119,6 +160,52 @@ impl ClientBuilder {
         self
     }
 
+    /// Sends `cookies`, keyed by name, along with every request to `webtoons.com`, in addition to the session.
+    ///
+    /// Some responses from `webtoons.com` depend on cookies other than the session, and without them the page that
+    /// comes back isn't the one that would be scraped:
+    ///
+    /// - `needGDPR`, `needCCPA`, and `needCOPPA`: When requesting from a region where consent is required, the landing,
+    ///   viewer, and listing pages can be served as a consent prompt until these are set to `FALSE`.
+    /// - `locale`: The search and home pages can redirect based on the locale cookie rather than the language in the
+    ///   URL.
+    ///
+    /// The dashboard, subscription, like, and post endpoints only need the session given with
+    /// [`ClientBuilder::with_session`]. A `NEO_SES` entry in `cookies` is ignored; use `with_session` for that.
+    ///
+    /// Requests to other hosts, like the image CDN panels are downloaded from, never carry these cookies.
+    ///
+    /// Names and values are checked when the `Client` is built, see [`ClientBuilder::build`].
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # use webtoon::platform::webtoons::ClientBuilder;
+    /// let cookies = HashMap::from([
+    ///     ("needGDPR".to_string(), "FALSE".to_string()),
+    ///     ("locale".to_string(), "en".to_string()),
+    /// ]);
+    ///
+    /// let client = ClientBuilder::new()
+    ///     .with_session("session-token")
+    ///     .cookies(cookies)
+    ///     .build()
+    ///     .expect("Failed to build Client");
+    /// ```
+    #[must_use]
+    pub fn cookies(self, cookies: HashMap<String, String>) -> Self {
+        let mut cookies: Vec<(String, String)> = cookies
+            .into_iter()
+            .filter(|(name, _)| name != "NEO_SES")
+            .collect();
+
+        // Keeps the header the same between runs, as a `HashMap` has no order.
+        cookies.sort_unstable();
+
+        Self { cookies, ..self }
+    }
+
     /// Sets a custom `User-Agent` header for the `Client`.
     ///
     /// Use this method when you want to specify a different `User-Agent` string for your API requests.
@@ -146,6 +233,217 @@ impl ClientBuilder {
         Self { builder, ..self }
     }
 
+    /// Sets the `Accept-Language` header sent with every request.
+    ///
+    /// The language of a webtoon's content comes from the [`Language`] in its URL, but strings webtoons.com fills in
+    /// on its own, like dates and status labels, follow this header. Setting it keeps those in one language, such as
+    /// English, while still scraping a `Language::Ko` webtoon, so parsing them doesn't depend on each webtoon's language.
+    ///
+    /// The value is sent as is, so anything valid for the header works, like `"en"` or `"en-US,en;q=0.9"`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// # use webtoon::platform::webtoons::ClientBuilder;
+    /// let client = ClientBuilder::new()
+    ///     .accept_language("en-US")
+    ///     .build()
+    ///     .expect("Failed to build Client");
+    /// ```
+    ///
+    /// ### Returns
+    ///
+    /// Returns the modified `ClientBuilder` with the `Accept-Language` header set. [`ClientBuilder::build`] fails if
+    /// `language` isn't a valid header value.
+    #[must_use]
+    pub fn accept_language(self, language: &str) -> Self {
+        Self {
+            accept_language: Some(Arc::from(language)),
+            ..self
+        }
+    }
+
+    /// Uses an already built `reqwest::Client` for requests instead of building one.
+    ///
+    /// This lets the `Client` share a connection pool and TLS configuration with the rest of an application.
+    ///
+    /// ### Ignored Settings
+    ///
+    /// The given client owns its own configuration, so anything that would have been baked into the built one is
+    /// ignored: [`ClientBuilder::user_agent`], [`ClientBuilder::accept_language`], the TLS backend features, and
+    /// `cookie_store`. The `webtoon/VERSION` user agent and HTTPS-only defaults are not applied either.
+    ///
+    /// A session from [`ClientBuilder::with_session`] and cookies from [`ClientBuilder::cookies`] are still used, as
+    /// they are sent along with each request.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// # use webtoon::platform::webtoons::ClientBuilder;
+    /// let http = reqwest::Client::new();
+    ///
+    /// let client = ClientBuilder::new()
+    ///     .http_client(http)
+    ///     .build()
+    ///     .expect("Failed to build Client");
+    /// ```
+    #[must_use]
+    pub fn http_client(self, client: reqwest::Client) -> Self {
+        Self {
+            http: Some(client),
+            ..self
+        }
+    }
+
+    /// Stops long-running operations of the built `Client` once `token` is cancelled.
+    ///
+    /// Dropping the future of an operation stops it as well, but panel downloads already spawned on the runtime keep
+    /// going until they finish. With a token, these check it and stop early, so something like a stop button takes
+    /// effect quickly.
+    ///
+    /// The token is checked between pages of [`Episode::posts`] and the other post getters, between the pages
+    /// [`Webtoon::episodes`] scrapes, and, with the `download` feature, between panels of `Episode::download` and
+    /// `Client::download_episodes`.
+    /// Once cancelled, these return [`ClientError::Cancelled`], wrapped in their own error type.
+    ///
+    /// The token applies to every `Webtoon` and `Episode` made from the built `Client`. Give a child token, from
+    /// [`CancellationToken::child_token`], to be able to cancel this `Client` without cancelling the parent.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// # use webtoon::platform::webtoons::ClientBuilder;
+    /// use tokio_util::sync::CancellationToken;
+    ///
+    /// let token = CancellationToken::new();
+    ///
+    /// let client = ClientBuilder::new()
+    ///     .cancellation_token(token.clone())
+    ///     .build()
+    ///     .expect("Failed to build Client");
+    ///
+    /// // Later, from a stop button:
+    /// token.cancel();
+    /// ```
+    #[must_use]
+    pub fn cancellation_token(self, token: CancellationToken) -> Self {
+        Self {
+            cancellation: Some(token),
+            ..self
+        }
+    }
+
+    /// Persists the session cookie to the file at `path`, so that a session refreshed by the server survives restarts.
+    ///
+    /// When the `Client` is built, the session saved in the file is loaded, and whenever `webtoons.com` responds with a new
+    /// `NEO_SES` cookie, the file is updated. If the file doesn't exist yet, it will be created when a session is first
+    /// saved.
+    ///
+    /// ### Precedence
+    ///
+    /// - If the file has a saved session, it takes precedence over one given with [`ClientBuilder::with_session`], as it's
+    ///   the most recently refreshed one.
+    /// - If the file has no saved session, the one from [`ClientBuilder::with_session`] is used and saved to the file.
+    ///
+    /// A session refreshed mid-run is used by the current `Client` from the next request on, and is saved for the next time
+    /// a `Client` is built.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::ClientBuilder;
+    /// let client = ClientBuilder::new()
+    ///     .with_session("session-token")
+    ///     .cookie_store("session.txt")
+    ///     .build()
+    ///     .expect("Failed to build Client");
+    /// ```
+    #[cfg(feature = "cookies")]
+    #[must_use]
+    pub fn cookie_store(self, path: impl AsRef<std::path::Path>) -> Self {
+        Self {
+            cookie_store: Some(path.as_ref().to_path_buf()),
+            ..self
+        }
+    }
+
+    /// Shares scraped episode pages between every [`Episode`] made from the built `Client`, keeping at most `capacity`
+    /// of the most recently used pages.
+    ///
+    /// Each `webtoon.episode(N)` and `webtoon.episodes()` call makes new episodes with their own empty cache, so going
+    /// through the same episodes more than once would normally scrape each page again. With a page cache, an episode page
+    /// that was already scraped by any episode is reused instead.
+    ///
+    /// Calling [`Episode::evict_cache`] also removes the page from this cache.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// # use webtoon::platform::webtoons::ClientBuilder;
+    /// let client = ClientBuilder::new()
+    ///     .page_cache(100)
+    ///     .build()
+    ///     .expect("Failed to build Client");
+    /// ```
+    #[must_use]
+    pub fn page_cache(self, capacity: usize) -> Self {
+        Self {
+            page_cache: Some(capacity),
+            ..self
+        }
+    }
+
+    /// Makes [`Client::webtoon`] remember the `Webtoon` handles it returns, so resolving the same id and type again
+    /// gives back a clone of the earlier handle rather than making a new request.
+    ///
+    /// Clones share their page cache, so whatever one has already fetched (title, views, etc.) is available to the
+    /// others too. Use [`Webtoon::evict_cache`] when fresh data is needed.
+    ///
+    /// Off by default, where every call fetches fresh.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// # use webtoon::platform::webtoons::ClientBuilder;
+    /// let client = ClientBuilder::new()
+    ///     .handle_cache(true)
+    ///     .build()
+    ///     .expect("Failed to build Client");
+    /// ```
+    #[must_use]
+    pub fn handle_cache(self, enabled: bool) -> Self {
+        Self {
+            handle_cache: enabled,
+            ..self
+        }
+    }
+
+    /// Makes the originals and canvas listing pages conditional requests, reusing the last fetched page when
+    /// webtoons.com answers `304 Not Modified`.
+    ///
+    /// The `ETag` and `Last-Modified` of each listing page are remembered, and sent back as `If-None-Match` and
+    /// `If-Modified-Since` the next time the same page is requested. As the listings rarely change, this mostly saves
+    /// downloading the same page again.
+    ///
+    /// Off by default, where every call downloads the page.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// # use webtoon::platform::webtoons::ClientBuilder;
+    /// let client = ClientBuilder::new()
+    ///     .listing_cache(true)
+    ///     .build()
+    ///     .expect("Failed to build Client");
+    /// ```
+    #[must_use]
+    pub fn listing_cache(self, enabled: bool) -> Self {
+        Self {
+            listing_cache: enabled,
+            ..self
+        }
+    }
+
     /// Consumes the `ClientBuilder` and returns a fully-configured `Client`.
     ///
     /// This method finalizes the configuration of the `ClientBuilder` and attempts to build
@@ -157,6 +455,10 @@ impl ClientBuilder {
     /// This method returns a [`ClientError`] if the underlying HTTP client could not be built,
     /// such as when TLS initialization fails or the DNS resolver cannot load the system configuration.
     ///
+    /// [`ClientError::InvalidCookie`] is returned if a cookie from [`ClientBuilder::cookies`] has a name that is empty
+    /// or contains a `=`, or a name or value that contains a `;`, whitespace, or a control character. Any of these
+    /// would let the cookie be read as a different cookie, like the session.
+    ///
     /// ### Example
     ///
     /// ```rust
@@ -167,13 +469,92 @@ impl ClientBuilder {
     /// ### Returns
     ///
     /// A `Result` containing the configured `Client` on success, or a `ClientError` on failure.
-    pub fn build(self) -> Result<Client, ClientError> {
-        Ok(Client {
-            http: self
+    #[cfg_attr(not(feature = "cookies"), allow(unused_mut))]
+    pub fn build(mut self) -> Result<Client, ClientError> {
+        if let Some((name, _)) = self
+            .cookies
+            .iter()
+            .find(|(name, value)| !is_valid_cookie(name, value))
+        {
+            return Err(ClientError::InvalidCookie(name.clone()));
+        }
+
+        let cookies: Option<Arc<str>> = (!self.cookies.is_empty()).then(|| {
+            self.cookies
+                .iter()
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect::<Vec<_>>()
+                .join("; ")
+                .into()
+        });
+
+        #[cfg(feature = "cookies")]
+        let mut session_jar = None;
+
+        #[cfg(feature = "cookies")]
+        if let Some(path) = self.cookie_store.as_ref().filter(|_| self.http.is_none()) {
+            let jar = cookies::SessionJar::load(path)
+                .map_err(|err| ClientError::Unexpected(err.into()))?;
+
+            match (jar.session(), &self.session) {
+                (Some(saved), _) => self.session = Some(saved),
+                (None, Some(session)) => jar
+                    .set_session(session.clone())
+                    .map_err(|err| ClientError::Unexpected(err.into()))?,
+                (None, None) => {}
+            }
+
+            let jar = Arc::new(jar.with_cookies(cookies.clone()));
+
+            self.builder = self.builder.cookie_provider(Arc::clone(&jar));
+            session_jar = Some(jar);
+        }
+
+        if let Some(cookies) = &cookies {
+            reqwest::header::HeaderValue::from_str(cookies)
+                .map_err(|err| ClientError::Unexpected(err.into()))?;
+        }
+
+        if let Some(language) = self
+            .accept_language
+            .as_ref()
+            .filter(|_| self.http.is_none())
+        {
+            let language = reqwest::header::HeaderValue::from_str(language)
+                .map_err(|err| ClientError::Unexpected(err.into()))?;
+
+            self.builder = self
+                .builder
+                .default_headers(reqwest::header::HeaderMap::from_iter([(
+                    reqwest::header::ACCEPT_LANGUAGE,
+                    language,
+                )]));
+        }
+
+        let http = match self.http {
+            Some(http) => http,
+            None => self
                 .builder
                 .build()
                 .map_err(|err| ClientError::Unexpected(err.into()))?,
+        };
+
+        Ok(Client {
+            http,
             session: self.session,
+            #[cfg(feature = "cookies")]
+            session_jar,
+            cookies,
+            page_cache: self
+                .page_cache
+                .map(|capacity| Arc::new(std::sync::Mutex::new(Lru::new(capacity)))),
+            handle_cache: self
+                .handle_cache
+                .then(|| Arc::new(std::sync::Mutex::new(HashMap::new()))),
+            listing_cache: self
+                .listing_cache
+                .then(|| Arc::new(std::sync::Mutex::new(HashMap::new()))),
+            cancellation: self.cancellation,
         })
     }
 }
@@ -200,6 +581,91 @@ impl ClientBuilder {
 pub struct Client {
     pub(super) http: reqwest::Client,
     pub(super) session: Option<Arc<str>>,
+    /// The store from [`ClientBuilder::cookie_store`], which holds the session once webtoons.com refreshes it.
+    #[cfg(feature = "cookies")]
+    session_jar: Option<Arc<cookies::SessionJar>>,
+    /// Extra cookies from [`ClientBuilder::cookies`], already joined into a `Cookie` header value.
+    pub(super) cookies: Option<Arc<str>>,
+    pub(super) page_cache: Option<PageCache>,
+    pub(super) handle_cache: Option<HandleCache>,
+    pub(super) listing_cache: Option<ListingCache>,
+    pub(super) cancellation: Option<CancellationToken>,
+}
+
+/// `Webtoon` handles returned by [`Client::webtoon`], keyed by id and type.
+///
+/// The stored handles hold a `Client` without this cache, otherwise the cache would keep itself alive.
+type HandleCache = Arc<std::sync::Mutex<HashMap<(u32, Type), Webtoon>>>;
+
+/// Listing pages keyed by URL, along with the validators to make the next request for them conditional.
+type ListingCache = Arc<std::sync::Mutex<HashMap<String, Listing>>>;
+
+#[derive(Debug, Clone)]
+pub(super) struct Listing {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    document: Arc<str>,
+}
+
+/// Whether `host` is `webtoons.com` or one of its subdomains, which are the only hosts cookies are sent to.
+pub(super) fn is_webtoons_host(host: &str) -> bool {
+    host == "webtoons.com" || host.ends_with(".webtoons.com")
+}
+
+/// Whether `name` and `value` can be sent as one cookie, without being read as more than one or as a different one.
+fn is_valid_cookie(name: &str, value: &str) -> bool {
+    let forbidden = |c: char| c == ';' || c.is_whitespace() || c.is_control();
+
+    !name.is_empty() && !name.contains(|c| c == '=' || forbidden(c)) && !value.contains(forbidden)
+}
+
+fn is_webtoons_url(url: &str) -> bool {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(is_webtoons_host))
+        .unwrap_or(false)
+}
+
+/// Returns why a response to the home page doesn't look like the home page, or `None` if it does.
+fn blocked(status: u16, host: &str, body: &str) -> Option<String> {
+    if status != 200 {
+        return Some(format!("home page responded with status `{status}`"));
+    }
+
+    if !is_webtoons_host(host) {
+        return Some(format!("home page redirected to `{host}`"));
+    }
+
+    // Every normal page links back to the home page with the logo, which block pages don't have. Checking this first
+    // keeps a normal page that happens to mention a captcha, like in a title, from being taken for a block page.
+    if body.contains("WEBTOON") {
+        return None;
+    }
+
+    let lowercase = body.to_lowercase();
+
+    for marker in [
+        "captcha",
+        "access denied",
+        "cf-chl",
+        "not available in your country",
+    ] {
+        if lowercase.contains(marker) {
+            return Some(format!("home page contained `{marker}`"));
+        }
+    }
+
+    Some("home page was missing the `WEBTOON` branding".to_string())
+}
+
+/// Approximates how webtoons.com turns a title into a url slug: `Tower of God` -> `tower-of-god`.
+fn slugify(title: &str) -> String {
+    title
+        .to_lowercase()
+        .split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
 }
 
 // Creation impls
@@ -255,6 +721,63 @@ impl Client {
             .expect("Client::with_session()")
     }
 
+    /// Instantiates a new [`Client`] that makes its requests with the given `reqwest::Client`.
+    ///
+    /// Unlike [`Client::new`], this can't panic, as no HTTP client has to be built. See [`ClientBuilder::http_client`]
+    /// for which settings are then left to the given client.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// # use webtoon::platform::webtoons::Client;
+    /// let http = reqwest::Client::new();
+    /// let client = Client::from_http_client(http);
+    /// ```
+    #[must_use]
+    pub fn from_http_client(client: reqwest::Client) -> Self {
+        Self {
+            http: client,
+            session: None,
+            #[cfg(feature = "cookies")]
+            session_jar: None,
+            cookies: None,
+            page_cache: None,
+            handle_cache: None,
+            listing_cache: None,
+            cancellation: None,
+        }
+    }
+
+    /// The environment variable [`Client::from_env`] reads the session from.
+    pub const SESSION_ENV: &'static str = "WEBTOONS_SESSION";
+
+    /// Instantiates a new [`Client`] with the session from the `WEBTOONS_SESSION` environment variable.
+    ///
+    /// If the variable is unset or empty, the client has no session, the same as [`Client::new`]. This keeps tools
+    /// run in CI or other automation from each having to read and pass the session along themselves.
+    ///
+    /// ### Panics
+    ///
+    /// Same as [`Client::new`], if the TLS backend or DNS resolver cannot be initialized.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// # use webtoon::platform::webtoons::Client;
+    /// let client = Client::from_env();
+    ///
+    /// if !client.has_session() {
+    ///     eprintln!("`WEBTOONS_SESSION` is not set, only public data can be gotten");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_env() -> Self {
+        match env::var(Self::SESSION_ENV) {
+            Ok(session) if !session.trim().is_empty() => Self::with_session(session.trim()),
+            _ => Self::new(),
+        }
+    }
+
     /// Returns a [`ClientBuilder`] for creating a custom-configured `Client`.
     ///
     /// The builder pattern allows for greater flexibility in configuring a `Client`.
@@ -318,7 +841,7 @@ impl Client {
         profile: &str,
         language: Language,
     ) -> Result<Option<Creator>, CreatorError> {
-        if matches!(language, Language::Zh | Language::De | Language::Fr) {
+        if !language.supports_profiles() {
             return Err(CreatorError::UnsupportedLanguage);
         }
 
@@ -335,6 +858,112 @@ impl Client {
         }))
     }
 
+    /// Fetches the creator profile for `profile` in every language that has one.
+    ///
+    /// Creators can have a presence on more than one language site, so this probes each language in [`Language::ALL`]
+    /// and collects the profiles that exist.
+    ///
+    /// ### Behavior
+    ///
+    /// - Languages that don't support creator profiles at all (see [`Language::supports_profiles`]) are skipped without a request.
+    /// - Languages where the profile doesn't exist, or was disabled by the creator, are left out of the result.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{ Client, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// for (language, creator) in client.creator_all_languages("_profile").await? {
+    ///     println!("{language:?}: {}", creator.username());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// Returns a [`CreatorError`] if any of the profile requests fails for a reason other than the profile not existing.
+    pub async fn creator_all_languages(
+        &self,
+        profile: &str,
+    ) -> Result<Vec<(Language, Creator)>, CreatorError> {
+        let mut creators = Vec::new();
+
+        for language in Language::ALL {
+            if !language.supports_profiles() {
+                continue;
+            }
+
+            match self.creator(profile, language).await {
+                Ok(Some(creator)) => creators.push((language, creator)),
+                Ok(None) | Err(CreatorError::DisabledByCreator) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(creators)
+    }
+
+    /// Returns the [`Creator`] credited as `username` on `webtoon`.
+    ///
+    /// Unlike [`Client::creator`], this doesn't need a profile page, so it also works for creators without an
+    /// account, like those of Korean and Chinese originals. These creators have a [`Creator::profile`] of `None`,
+    /// [`Creator::webtoons`] and [`Creator::followers`] return `None`, but [`Creator::username`] works like for any
+    /// other creator.
+    ///
+    /// `username` is matched ignoring case and extra whitespace.
+    ///
+    /// ### Returns
+    ///
+    /// - `Ok(Some(Creator))`: The creator as credited on the webtoon, with a profile if they have one.
+    /// - `Ok(None)`: No creator on the webtoon goes by `username`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{errors::Error, Client, Type};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = Client::new();
+    ///
+    /// if let Some(webtoon) = client.webtoon(95, Type::Original).await? {
+    ///     if let Some(creator) = client.creator_on_webtoon("SIU", &webtoon).await? {
+    ///         println!("{} has a profile: {}", creator.username(), creator.profile().is_some());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `WebtoonError::ClientError`: If the webtoon page could not be fetched.
+    /// - `WebtoonError::Unexpected`: If the creators could not be found on the webtoon page.
+    pub async fn creator_on_webtoon(
+        &self,
+        username: &str,
+        webtoon: &Webtoon,
+    ) -> Result<Option<Creator>, WebtoonError> {
+        let normalize = |name: &str| {
+            name.split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase()
+        };
+
+        let username = normalize(username);
+
+        let creator = webtoon
+            .creators()
+            .await?
+            .into_iter()
+            .find(|creator| normalize(creator.username()) == username);
+
+        Ok(creator)
+    }
+
     /// Searches for webtoons on Webtoons.com based on a query string and language.
     ///
     /// This method performs a search on the Webtoons platform using the provided query string and language.
@@ -350,7 +979,8 @@ impl Client {
     ///
     /// Returns a `Result<Vec<Item>, SearchError>` containing:
     ///
-    /// - `Ok(Vec<Item>)`: A vector of `Item` objects that match the search query in the specified language.
+    /// - `Ok(Vec<Item>)`: A vector of `Item` objects that match the search query in the specified language. Originals
+    ///   come first, then Canvas, each in the order the search ranked them; see [`Item::rank`].
     /// - `Err(SearchError)`: An error if the search request fails (e.g., due to network issues or a rate limit being exceeded).
     ///
     /// ### Example
@@ -405,7 +1035,7 @@ impl Client {
         // - WEBTOON
         let url = format!("https://www.webtoons.com/p/api/community/v1/content/TITLE/GW/search?criteria=KEYWORD_SEARCH&contentSubType=WEBTOON&nextSize=50&language={lang}&query={query}");
 
-        let response = self.http.get(url).send().await?;
+        let response = self.get(&url).send_traced().await?;
 
         let api = serde_json::from_str::<search::Api>(&response.text().await?)
             .context("Failed to deserialize search api response")?;
@@ -417,27 +1047,13 @@ impl Client {
         };
 
         for data in originals.data {
-            let id: u32 = data
-                .content_id
-                .parse()
-                .context("Failed to parse webtoon id to u32")?;
-
-            let webtoon = Item {
-                client: self.clone(),
-                id,
-                r#type: Type::Original,
-                title: data.name,
-                thumbnail: format!("https://swebtoon-phinf.pstatic.net{}", data.thumbnail.path),
-                creator: data.extra.writer.nickname,
-            };
-
-            webtoons.push(webtoon);
+            webtoons.push(Item::new(self, Type::Original, data)?);
         }
 
         let mut next = originals.pagination.next;
         while let Some(ref cursor) = next {
             let url = format!("https://www.webtoons.com/p/api/community/v1/content/TITLE/GW/search?criteria=KEYWORD_SEARCH&contentSubType=WEBTOON&nextSize=50&language={lang}&query={query}&cursor={cursor}");
-            let response = self.http.get(url).send().await?;
+            let response = self.get(&url).send_traced().await?;
 
             let api = serde_json::from_str::<search::Api>(&response.text().await?)
                 .context("Failed to deserialize search api response")?;
@@ -449,28 +1065,14 @@ impl Client {
             };
 
             for data in originals.data {
-                let id: u32 = data
-                    .content_id
-                    .parse()
-                    .context("Failed to parse webtoon id to u32")?;
-
-                let webtoon = Item {
-                    client: self.clone(),
-                    id,
-                    r#type: Type::Original,
-                    title: data.name,
-                    thumbnail: format!("https://swebtoon-phinf.pstatic.net{}", data.thumbnail.path),
-                    creator: data.extra.writer.nickname,
-                };
-
-                webtoons.push(webtoon);
+                webtoons.push(Item::new(self, Type::Original, data)?);
             }
             next = originals.pagination.next;
         }
 
         let url = format!("https://www.webtoons.com/p/api/community/v1/content/TITLE/GW/search?criteria=KEYWORD_SEARCH&contentSubType=CHALLENGE&nextSize=50&language={lang}&query={query}");
 
-        let response = self.http.get(url).send().await?;
+        let response = self.get(&url).send_traced().await?;
 
         let api = serde_json::from_str::<search::Api>(&response.text().await?)
             .context("Failed to deserialize search api response")?;
@@ -482,27 +1084,13 @@ impl Client {
         };
 
         for data in canvas.data {
-            let id: u32 = data
-                .content_id
-                .parse()
-                .context("Failed to parse webtoon id to u32")?;
-
-            let webtoon = Item {
-                client: self.clone(),
-                id,
-                r#type: Type::Canvas,
-                title: data.name,
-                thumbnail: format!("https://swebtoon-phinf.pstatic.net{}", data.thumbnail.path),
-                creator: data.extra.writer.nickname,
-            };
-
-            webtoons.push(webtoon);
+            webtoons.push(Item::new(self, Type::Canvas, data)?);
         }
 
         let mut next = canvas.pagination.next;
         while let Some(ref cursor) = next {
             let url = format!("https://www.webtoons.com/p/api/community/v1/content/TITLE/GW/search?criteria=KEYWORD_SEARCH&contentSubType=CHALLENGE&nextSize=50&language={lang}&query={query}&cursor={cursor}");
-            let response = self.http.get(url).send().await?;
+            let response = self.get(&url).send_traced().await?;
 
             let api = serde_json::from_str::<search::Api>(&response.text().await?)
                 .context("Failed to deserialize search api response")?;
@@ -514,25 +1102,21 @@ impl Client {
             };
 
             for data in canvas.data {
-                let id: u32 = data
-                    .content_id
-                    .parse()
-                    .context("Failed to parse webtoon id to u32")?;
-
-                let webtoon = Item {
-                    client: self.clone(),
-                    id,
-                    r#type: Type::Canvas,
-                    title: data.name,
-                    thumbnail: format!("https://swebtoon-phinf.pstatic.net{}", data.thumbnail.path),
-                    creator: data.extra.writer.nickname,
-                };
-
-                webtoons.push(webtoon);
+                webtoons.push(Item::new(self, Type::Canvas, data)?);
             }
             next = canvas.pagination.next;
         }
 
+        for r#type in [Type::Original, Type::Canvas] {
+            for (rank, item) in webtoons
+                .iter_mut()
+                .filter(|item| item.r#type == r#type)
+                .enumerate()
+            {
+                item.rank = rank + 1;
+            }
+        }
+
         Ok(webtoons)
     }
 
@@ -581,6 +1165,37 @@ impl Client {
         originals::scrape(self, language).await
     }
 
+    /// Retrieves a lightweight listing of the "Originals" webtoons for the specified language.
+    ///
+    /// Unlike [`Client::originals`], each entry also carries the title and thumbnail shown on the card, which makes
+    /// browsing the list possible without touching each webtoon's own page. Use [`WebtoonListing::into_webtoon`] to
+    /// get a full [`Webtoon`] for the entries of interest.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{errors::Error, Client, Language};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = Client::new();
+    ///
+    /// for listing in client.originals_listing(Language::En).await? {
+    ///     println!("{} ({}): {}", listing.title(), listing.id(), listing.thumbnail());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// Returns an error if the originals page could not be fetched, or if a card was missing its link or thumbnail.
+    pub async fn originals_listing(
+        &self,
+        language: Language,
+    ) -> Result<Vec<WebtoonListing>, OriginalsError> {
+        originals::listing(self, language).await
+    }
+
     /// Retrieves a list of "Canvas" webtoons for the specified language from Webtoons.com,
     /// with support for pagination and sorting options.
     ///
@@ -651,6 +1266,64 @@ impl Client {
         canvas::scrape(self, language, pages, sort).await
     }
 
+    /// Returns the language version of webtoons.com that the webtoon with the given `id` and `type` is on.
+    ///
+    /// Ids are only unique within a language, and webtoons.com redirects a bare id to the language it belongs to. This
+    /// is what [`Client::webtoon`] relies on, and is exposed here for ids gathered from other language versions of the
+    /// site, so a correctly scoped handle can be made with [`Client::webtoon_from_url`] or similar.
+    ///
+    /// ### Returns
+    ///
+    /// - `Ok(Some(Language))`: The language the id redirected to.
+    /// - `Ok(None)`: If there is no webtoon with this id and type.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{errors::Error, Type, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// if let Some(language) = client.detect_language(95, Type::Original).await? {
+    ///     println!("Tower of God is on the `{language}` site");
+    /// }
+    /// # Ok(())}
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `ClientError`: If the request fails, or the URL redirected to has no known language.
+    pub async fn detect_language(
+        &self,
+        id: u32,
+        r#type: Type,
+    ) -> Result<Option<Language>, ClientError> {
+        let url = format!(
+            "https://www.webtoons.com/*/{}/*/list?title_no={id}",
+            match r#type {
+                Type::Original => "*",
+                Type::Canvas => "canvas",
+            }
+        );
+
+        let response = self.get(&url).send_traced().await?;
+
+        if response.status() == 404 {
+            return Ok(None);
+        }
+
+        let segment = response
+            .url()
+            .path_segments()
+            .and_then(|mut segments| segments.next())
+            .context("webtoon url should have a language segment as its first")?;
+
+        let language = Language::from_str(segment)
+            .context("Failed to parse return URL segment into `Language` enum")?;
+
+        Ok(Some(language))
+    }
+
     /// Constructs a `Webtoon` from the given `id` and `type`.
     ///
     /// ### Parameters
@@ -681,6 +1354,10 @@ impl Client {
     /// # Ok(())}
     /// ```
     pub async fn webtoon(&self, id: u32, r#type: Type) -> Result<Option<Webtoon>, WebtoonError> {
+        if let Some(webtoon) = self.cached_handle(id, r#type) {
+            return Ok(Some(webtoon));
+        }
+
         let url = format!(
             "https://www.webtoons.com/*/{}/*/list?title_no={id}",
             match r#type {
@@ -689,7 +1366,7 @@ impl Client {
             }
         );
 
-        let response = self.http.get(&url).send().await?;
+        let response = self.get(&url).send_traced().await?;
 
         // Webtoon doesn't exist
         if response.status() == 404 {
@@ -731,8 +1408,11 @@ impl Client {
             scope,
             slug: Arc::from(slug),
             page: Arc::new(Mutex::new(None)),
+            genres: None,
         };
 
+        self.cache_handle(&webtoon);
+
         Ok(Some(webtoon))
     }
 
@@ -776,6 +1456,7 @@ impl Client {
     ///
     /// - The URL must be a valid Webtoons.com URL, otherwise the function will return a `WebtoonError`.
     /// - The method expects the `title_no` query parameter to be present in the URL, as this is how the webtoon ID is identified.
+    /// - No request is made, so the scope in the URL is trusted as is. Use [`Webtoon::verify`] to confirm it with webtoons.com.
     ///
     /// ### Errors
     ///
@@ -827,11 +1508,78 @@ impl Client {
             slug: Arc::from(slug),
             id,
             page: Arc::new(Mutex::new(None)),
+            genres: None,
         };
 
         Ok(webtoon)
     }
 
+    /// Resolves the url slug of a webtoon, e.g. `tower-of-god`, to its `title_no`.
+    ///
+    /// Links shared outside the site sometimes have only the slug. There is no endpoint that looks up a slug
+    /// directly, so the slug is turned back into a search query, and the results of the matching [`Type`] are
+    /// resolved until one with the same slug and language is found. Results whose title reads like the slug are
+    /// tried first, which usually means a single extra request.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{errors::Error, Client, Language, Type};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = Client::new();
+    ///
+    /// if let Some(id) = client.title_no_for_slug(Language::En, Type::Original, "tower-of-god").await? {
+    ///     let webtoon = client.webtoon(id, Type::Original).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// Returns a [`ClientError`] if the search or resolving one of its results fails.
+    pub async fn title_no_for_slug(
+        &self,
+        language: Language,
+        r#type: Type,
+        slug: &str,
+    ) -> Result<Option<u32>, ClientError> {
+        let slug = slug.trim_matches('/').to_lowercase();
+
+        let mut candidates: Vec<Item> = self
+            .search(&slug.replace('-', " "), language)
+            .await
+            .map_err(|err| match err {
+                SearchError::ClientError(err) => err,
+                err => ClientError::Unexpected(err.into()),
+            })?
+            .into_iter()
+            .filter(|item| item.r#type() == r#type)
+            .collect();
+
+        // `false` sorts first, so titles that read like the slug are tried before the rest.
+        candidates.sort_by_key(|item| slugify(item.title()) != slug);
+
+        for item in candidates {
+            let webtoon =
+                self.webtoon(item.id(), item.r#type())
+                    .await
+                    .map_err(|err| match err {
+                        WebtoonError::ClientError(err) => err,
+                        err => ClientError::Unexpected(err.into()),
+                    })?;
+
+            if let Some(webtoon) = webtoon {
+                if webtoon.language == language && webtoon.slug.eq_ignore_ascii_case(&slug) {
+                    return Ok(Some(webtoon.id));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
     /// Returns user info derived from the passed in session.
     ///
     /// This can be useful if you need to get the profile or username from the session alone.
@@ -843,8 +1591,8 @@ impl Client {
         let user_info: UserInfo = self
             .http
             .get("https://www.webtoons.com/en/member/userInfo")
-            .header("Cookie", format!("NEO_SES={session}"))
-            .send()
+            .header("Cookie", self.session_cookie(session))
+            .send_traced()
             .await?
             .json()
             .await
@@ -852,47 +1600,267 @@ impl Client {
         Ok(user_info)
     }
 
-    /// Returns if the client was provided a session.
-    ///
-    /// This does **NOT** mean session is valid.
-    pub fn has_session(&self) -> bool {
-        self.session.is_some()
+    /// Returns if the client was provided a session.
+    ///
+    /// This does **NOT** mean session is valid.
+    pub fn has_session(&self) -> bool {
+        self.session().is_some()
+    }
+
+    /// Tries to validate the current session.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(true)` if the session is valid.
+    /// - `Ok(false)` if the session is not valid.
+    ///
+    /// # Note
+    ///
+    /// This is mainly provided for a quick early return for when the circumstances allow, and any methods that use a
+    /// session should not rely on the session always being valid after this check; The session could be invalidated
+    /// after the check completes.
+    ///
+    /// # Errors
+    ///
+    /// [`ClientError::NoSessionProvided`] if session was never proivided.
+    /// [`ClientError::Unexpected`] if there was an error in request or deserialization.
+    pub async fn has_valid_session(&self) -> Result<bool, ClientError> {
+        let Some(session) = self.session() else {
+            return Err(ClientError::NoSessionProvided);
+        };
+
+        let user_info = self.user_info_for_session(&session).await?;
+
+        Ok(user_info.is_logged_in)
+    }
+
+    /// Returns the webtoons the session user is subscribed to on the `language` site.
+    ///
+    /// This reads the user's subscribed list on webtoons.com in a single request, which is much cheaper than calling
+    /// [`Webtoon::is_subscribed`] on every webtoon of a library. No webtoon page is fetched; the returned `Webtoon`s
+    /// only fetch their page once a method needs it.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{errors::Error, Client, Language};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = Client::with_session("my-session");
+    ///
+    /// for webtoon in client.subscriptions(Language::En).await? {
+    ///     println!("subscribed to {}", webtoon.id());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - [`ClientError::NoSessionProvided`] if the client has no session.
+    /// - [`ClientError::InvalidSession`] if the session is invalid or expired.
+    /// - [`ClientError::Unexpected`] if the request failed or a subscribed webtoon's link couldn't be parsed.
+    pub async fn subscriptions(&self, language: Language) -> Result<Vec<Webtoon>, ClientError> {
+        let document = self.get_favorites_page(language).await?;
+
+        subscriptions(self, &Html::parse_document(&document))
+    }
+
+    /// Checks that webtoons.com can be reached and is serving normal pages to this client.
+    ///
+    /// Fetches the English home page and looks at the status, the host it ended up on, and the body. This is meant as
+    /// a cheap precondition before a large scrape, where finding out the egress is geo-blocked or hitting a bot-wall
+    /// halfway through is more costly.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{errors::ClientError, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), ClientError> {
+    /// let client = Client::new();
+    ///
+    /// if let Err(ClientError::Blocked(reason)) = client.health_check().await {
+    ///     eprintln!("webtoons.com is blocking this connection: {reason}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - [`ClientError::Blocked`] if the response looks like a geo-block or bot-wall instead of the home page.
+    /// - [`ClientError::RateLimitExceeded`] if the site responded with `429`.
+    /// - Any of the connection errors, like [`ClientError::Dns`] or [`ClientError::Tls`], if the site couldn't be reached.
+    pub async fn health_check(&self) -> Result<(), ClientError> {
+        let response = self
+            .get("https://www.webtoons.com/en/")
+            .send_traced()
+            .await
+            .map_err(ClientError::from)?;
+
+        if response.status() == 429 {
+            let retry_after = response
+                .headers()
+                .get("Retry-After")
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(5);
+
+            return Err(ClientError::RateLimitExceeded(retry_after));
+        }
+
+        let status = response.status().as_u16();
+        let host = response.url().host_str().unwrap_or_default().to_owned();
+        let body = response.text().await?;
+
+        match blocked(status, &host, &body) {
+            Some(reason) => Err(ClientError::Blocked(reason)),
+            None => Ok(()),
+        }
+    }
+
+    /// Downloads the panels for several episodes at once, keeping at most `concurrency` panel requests in flight.
+    ///
+    /// Downloading episodes one by one with [`Episode::download`] caps pressure per episode only. Here a single limit
+    /// is shared across the whole batch, and all requests go through this client's connection pool, making it the one
+    /// place to tune when archiving an entire series.
+    ///
+    /// The returned [`Panels`] are in the same order as `episodes`. A `concurrency` of `0` is treated as `1`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{errors::Error, Type, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = Client::new();
+    ///
+    /// if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    ///     let episodes = webtoon.episodes().await?;
+    ///     let episodes: Vec<_> = episodes.into_iter().collect();
+    ///
+    ///     for (episode, panels) in episodes.iter().zip(client.download_episodes(&episodes, 16).await?) {
+    ///         panels.save_single(&format!("{}", episode.number())).await?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// Returns a [`DownloadError`] if any episode page or panel fails to be fetched. Nothing is returned for the
+    /// episodes that did succeed.
+    #[cfg(feature = "download")]
+    pub async fn download_episodes(
+        &self,
+        episodes: &[Episode],
+        concurrency: usize,
+    ) -> Result<Vec<Panels>, DownloadError> {
+        episode::download_all(self, episodes, concurrency).await
+    }
+}
+
+// Internal only impls
+impl Client {
+    /// Returns `ClientError::Cancelled` if the token from [`ClientBuilder::cancellation_token`] was cancelled.
+    pub(super) fn check_cancelled(&self) -> Result<(), ClientError> {
+        match &self.cancellation {
+            Some(token) if token.is_cancelled() => Err(ClientError::Cancelled),
+            _ => Ok(()),
+        }
+    }
+
+    /// Runs `future` to completion, unless the cancellation token is cancelled first.
+    #[cfg(feature = "download")]
+    pub(super) async fn until_cancelled<F: std::future::Future>(
+        &self,
+        future: F,
+    ) -> Result<F::Output, ClientError> {
+        match &self.cancellation {
+            Some(token) => token
+                .run_until_cancelled(future)
+                .await
+                .ok_or(ClientError::Cancelled),
+            None => Ok(future.await),
+        }
+    }
+
+    /// Starts a `GET` request to `url` that is made without the session.
+    ///
+    /// Requests to `webtoons.com` carry the extra cookies from [`ClientBuilder::cookies`], while those to other hosts,
+    /// like the image CDN, go out without them. Requests made with the session get them from
+    /// [`Client::session_cookie`] instead.
+    pub(super) fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        let request = self.http.get(url);
+
+        match &self.cookies {
+            Some(cookies) if is_webtoons_url(url) => {
+                request.header(reqwest::header::COOKIE, cookies.as_ref())
+            }
+            _ => request,
+        }
+    }
+
+    /// The session to make requests with.
+    ///
+    /// With a [`ClientBuilder::cookie_store`], this is the session in the store, so that a session refreshed by
+    /// webtoons.com is used from the next request on, rather than only after a restart.
+    pub(super) fn session(&self) -> Option<Arc<str>> {
+        #[cfg(feature = "cookies")]
+        if let Some(session) = self.session_jar.as_ref().and_then(|jar| jar.session()) {
+            return Some(session);
+        }
+
+        self.session.clone()
+    }
+
+    /// The `Cookie` header value for a request made with `session`, along with any extra cookies.
+    pub(super) fn session_cookie(&self, session: &str) -> String {
+        match &self.cookies {
+            Some(cookies) => format!("NEO_SES={session}; {cookies}"),
+            None => format!("NEO_SES={session}"),
+        }
+    }
+
+    fn cached_handle(&self, id: u32, r#type: Type) -> Option<Webtoon> {
+        let cache = self.handle_cache.as_ref()?;
+
+        let webtoon = cache
+            .lock()
+            .expect("handle cache lock should not be poisoned")
+            .get(&(id, r#type))
+            .cloned()?;
+
+        Some(Webtoon {
+            client: self.clone(),
+            ..webtoon
+        })
     }
 
-    /// Tries to validate the current session.
-    ///
-    /// # Returns
-    ///
-    /// - `Ok(true)` if the session is valid.
-    /// - `Ok(false)` if the session is not valid.
-    ///
-    /// # Note
-    ///
-    /// This is mainly provided for a quick early return for when the circumstances allow, and any methods that use a
-    /// session should not rely on the session always being valid after this check; The session could be invalidated
-    /// after the check completes.
-    ///
-    /// # Errors
-    ///
-    /// [`ClientError::NoSessionProvided`] if session was never proivided.
-    /// [`ClientError::Unexpected`] if there was an error in request or deserialization.
-    pub async fn has_valid_session(&self) -> Result<bool, ClientError> {
-        let Some(session) = &self.session else {
-            return Err(ClientError::NoSessionProvided);
+    fn cache_handle(&self, webtoon: &Webtoon) {
+        let Some(cache) = &self.handle_cache else {
+            return;
         };
 
-        let user_info = self.user_info_for_session(session).await?;
+        let webtoon = Webtoon {
+            client: Self {
+                handle_cache: None,
+                ..self.clone()
+            },
+            ..webtoon.clone()
+        };
 
-        Ok(user_info.is_logged_in)
+        cache
+            .lock()
+            .expect("handle cache lock should not be poisoned")
+            .insert((webtoon.id, webtoon.r#type()), webtoon);
     }
-}
 
-// Internal only impls
-impl Client {
-    pub(super) async fn get_originals_page(&self, lang: Language) -> Result<Response, ClientError> {
+    pub(super) async fn get_originals_page(&self, lang: Language) -> Result<String, ClientError> {
         let url = format!("https://www.webtoons.com/{lang}/originals");
-        let response = self.http.get(url).send().await?;
-        Ok(response)
+        self.get_listing(url).await
     }
 
     pub(super) async fn get_canvas_page(
@@ -900,14 +1868,73 @@ impl Client {
         lang: Language,
         page: u16,
         sort: Sort,
-    ) -> Result<Response, ClientError> {
+    ) -> Result<String, ClientError> {
         let url = format!(
             "https://www.webtoons.com/{lang}/canvas/list?genreTab=ALL&sortOrder={sort}&page={page}"
         );
 
-        let response = self.http.get(url).send().await?;
+        self.get_listing(url).await
+    }
+
+    /// Gets the document of a listing page, going through the listing cache if it is enabled.
+    async fn get_listing(&self, url: String) -> Result<String, ClientError> {
+        let Some(cache) = &self.listing_cache else {
+            return Ok(self.get(&url).send_traced().await?.text().await?);
+        };
 
-        Ok(response)
+        let cached = cache
+            .lock()
+            .expect("listing cache lock should not be poisoned")
+            .get(&url)
+            .cloned();
+
+        let mut request = self.get(&url);
+
+        if let Some(cached) = &cached {
+            if let Some(etag) = &cached.etag {
+                request = request.header("If-None-Match", etag);
+            }
+
+            if let Some(last_modified) = &cached.last_modified {
+                request = request.header("If-Modified-Since", last_modified);
+            }
+        }
+
+        let response = request.send_traced().await?;
+
+        if let Some(cached) = cached.filter(|_| response.status() == 304) {
+            return Ok(cached.document.to_string());
+        }
+
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+
+        let etag = header("ETag");
+        let last_modified = header("Last-Modified");
+        let status = response.status();
+
+        let document = response.text().await?;
+
+        if status == 200 && (etag.is_some() || last_modified.is_some()) {
+            cache
+                .lock()
+                .expect("listing cache lock should not be poisoned")
+                .insert(
+                    url,
+                    Listing {
+                        etag,
+                        last_modified,
+                        document: Arc::from(document.as_str()),
+                    },
+                );
+        }
+
+        Ok(document)
     }
 
     pub(super) async fn get_creator_page(
@@ -916,10 +1943,47 @@ impl Client {
         profile: &str,
     ) -> Result<Response, ClientError> {
         let url = format!("https://www.webtoons.com/p/community/{lang}/u/{profile}");
-        let response = self.http.get(url).send().await?;
+        let response = self.get(&url).send_traced().await?;
         Ok(response)
     }
 
+    /// Asks webtoons.com what type the webtoon with the given id is when looked up as `r#type`.
+    ///
+    /// Returns `None` when there is no such webtoon of that type.
+    pub(super) async fn get_webtoon_type(
+        &self,
+        language: Language,
+        id: u32,
+        r#type: Type,
+    ) -> Result<Option<Type>, ClientError> {
+        let scope = match r#type {
+            Type::Original => "*",
+            Type::Canvas => "canvas",
+        };
+
+        let url = format!("https://www.webtoons.com/{language}/{scope}/*/list?title_no={id}");
+
+        let response = self.get(&url).send_traced().await?;
+
+        if response.status() == 404 {
+            return Ok(None);
+        }
+
+        let scope = response
+            .url()
+            .path_segments()
+            .and_then(|mut segments| segments.nth(1))
+            .context("webtoon url should have a scope segment as its second")?;
+
+        let r#type = match Scope::from_str(scope) {
+            Ok(Scope::Canvas) => Type::Canvas,
+            Ok(Scope::Original(_)) => Type::Original,
+            Err(err) => return Err(ClientError::Unexpected(err.into())),
+        };
+
+        Ok(Some(r#type))
+    }
+
     pub(super) async fn get_webtoon_page(
         &self,
         webtoon: &Webtoon,
@@ -936,7 +2000,30 @@ impl Client {
             format!("https://www.webtoons.com/{lang}/{scope}/{slug}/list?title_no={id}")
         };
 
-        let response = self.http.get(url).send().await?;
+        let response = self.get(&url).send_traced().await?;
+
+        Ok(response)
+    }
+
+    /// Gets the whole episode list from the JSON API the mobile site uses.
+    pub(super) async fn get_mobile_episodes(
+        &self,
+        webtoon: &Webtoon,
+    ) -> Result<Response, ClientError> {
+        let id = webtoon.id;
+
+        let r#type = match webtoon.scope {
+            Scope::Original(_) => "webtoon",
+            Scope::Canvas => "canvas",
+        };
+
+        let url = format!("https://m.webtoons.com/api/v1/{type}/{id}/episodes?pageSize=99999");
+
+        let response = self
+            .get(&url)
+            .header("Referer", "https://m.webtoons.com/")
+            .send_traced()
+            .await?;
 
         Ok(response)
     }
@@ -949,7 +2036,7 @@ impl Client {
             return Err(ClientError::InvalidSession);
         };
 
-        let session = self.session.as_ref().unwrap();
+        let session = self.session().unwrap();
 
         let mut form = HashMap::new();
         form.insert("titleNo", webtoon.id.to_string());
@@ -964,9 +2051,9 @@ impl Client {
             .post(url)
             .header("Referer", "https://www.webtoons.com/")
             .header("Service-Ticket-Id", "epicom")
-            .header("Cookie", format!("NEO_SES={session}"))
+            .header("Cookie", self.session_cookie(&session))
             .form(&form)
-            .send()
+            .send_traced()
             .await?;
 
         Ok(())
@@ -980,7 +2067,7 @@ impl Client {
             return Err(ClientError::InvalidSession);
         };
 
-        let session = self.session.as_ref().unwrap();
+        let session = self.session().unwrap();
 
         let mut form = HashMap::new();
         form.insert("titleNo", webtoon.id.to_string());
@@ -995,11 +2082,52 @@ impl Client {
             .post(url)
             .header("Referer", "https://www.webtoons.com/")
             .header("Service-Ticket-Id", "epicom")
-            .header("Cookie", format!("NEO_SES={session}"))
+            .header("Cookie", self.session_cookie(&session))
+            .form(&form)
+            .send_traced()
+            .await?;
+
+        Ok(())
+    }
+
+    pub(super) async fn post_set_notifications_for_webtoon(
+        &self,
+        webtoon: &Webtoon,
+        notify: bool,
+    ) -> Result<(), ClientError> {
+        if !self.has_valid_session().await? {
+            return Err(ClientError::InvalidSession);
+        };
+
+        let session = self.session().unwrap();
+
+        let mut form = HashMap::new();
+        form.insert("titleNo", webtoon.id.to_string());
+        form.insert("alarm", if notify { "Y" } else { "N" }.to_string());
+
+        // NOTE: Same `setFavorite` convention, where canvas is under `/challenge`.
+        let url = match webtoon.scope {
+            Scope::Original(_) => "https://www.webtoons.com/setAlarm",
+            Scope::Canvas => "https://www.webtoons.com/challenge/setAlarm",
+        };
+
+        let response = self
+            .http
+            .post(url)
+            .header("Referer", "https://www.webtoons.com/")
+            .header("Service-Ticket-Id", "epicom")
+            .header("Cookie", self.session_cookie(&session))
             .form(&form)
-            .send()
+            .send_traced()
             .await?;
 
+        if !response.status().is_success() {
+            return Err(ClientError::Unexpected(anyhow!(
+                "failed to set notification preference: {}",
+                response.status()
+            )));
+        }
+
         Ok(())
     }
 
@@ -1021,15 +2149,18 @@ impl Client {
         form.insert("titleNo", webtoon.id.to_string());
         form.insert("score", rating.to_string());
 
-        let session = self.session.as_ref().unwrap();
+        let session = self.session().unwrap();
 
         self.http
             .post(url)
             .header("Referer", "https://www.webtoons.com/")
             // NOTE: `wtu` just has to have something as a value and it works
-            .header("Cookie", format!("NEO_SES={session}; wtu=WTU"))
+            .header(
+                "Cookie",
+                format!("{}; wtu=WTU", self.session_cookie(&session)),
+            )
             .form(&form)
-            .send()
+            .send_traced()
             .await?;
 
         Ok(())
@@ -1040,7 +2171,7 @@ impl Client {
         webtoon: &Webtoon,
         page: u16,
     ) -> Result<Response, ClientError> {
-        let Some(session) = &self.session else {
+        let Some(session) = self.session() else {
             return Err(ClientError::NoSessionProvided);
         };
 
@@ -1053,8 +2184,8 @@ impl Client {
         let response = self
             .http
             .get(url)
-            .header("Cookie", format!("NEO_SES={session}"))
-            .send()
+            .header("Cookie", self.session_cookie(&session))
+            .send_traced()
             .await?;
 
         Ok(response)
@@ -1064,7 +2195,7 @@ impl Client {
         &self,
         webtoon: &Webtoon,
     ) -> Result<Response, ClientError> {
-        let Some(session) = &self.session else {
+        let Some(session) = self.session() else {
             return Err(ClientError::NoSessionProvided);
         };
 
@@ -1080,8 +2211,8 @@ impl Client {
         let response = self
             .http
             .get(url)
-            .header("Cookie", format!("NEO_SES={session}"))
-            .send()
+            .header("Cookie", self.session_cookie(&session))
+            .send_traced()
             .await?;
         Ok(response)
     }
@@ -1102,7 +2233,7 @@ impl Client {
 
         let url = format!("https://www.webtoons.com/{language}/{scope}/{slug}/rss?title_no={id}");
 
-        let response = self.http.get(url).send().await?;
+        let response = self.get(&url).send_traced().await?;
 
         Ok(response)
     }
@@ -1120,7 +2251,32 @@ impl Client {
             "https://www.webtoons.com/*/{scope}/*/*/viewer?title_no={id}&episode_no={episode}"
         );
 
-        let response = self.http.get(url).send().await?;
+        let response = self.get(&url).send_traced().await?;
+
+        Ok(response)
+    }
+
+    /// Same as [`Client::get_episode`], but as the session user, so the episodes they unlocked can be viewed.
+    pub(super) async fn get_episode_with_session(
+        &self,
+        webtoon: &Webtoon,
+        episode: u16,
+    ) -> Result<Response, ClientError> {
+        let session = self.session().ok_or(ClientError::NoSessionProvided)?;
+
+        let id = webtoon.id;
+        let scope = webtoon.scope.as_slug();
+
+        let url = format!(
+            "https://www.webtoons.com/*/{scope}/*/*/viewer?title_no={id}&episode_no={episode}"
+        );
+
+        let response = self
+            .http
+            .get(url)
+            .header("Cookie", self.session_cookie(&session))
+            .send_traced()
+            .await?;
 
         Ok(response)
     }
@@ -1129,29 +2285,40 @@ impl Client {
         &self,
         episode: &Episode,
     ) -> Result<Response, ClientError> {
-        let session = self
-            .session
-            .as_ref()
-            .map(|session| session.as_ref())
-            .unwrap_or_default();
+        self.get_likes_for_episodes(&episode.webtoon, &[episode.number])
+            .await
+    }
 
-        let scope = match episode.webtoon.scope {
+    /// Requests the like counts of all `episodes` of `webtoon` in a single call, with the content ids comma separated.
+    pub(super) async fn get_likes_for_episodes(
+        &self,
+        webtoon: &Webtoon,
+        episodes: &[u16],
+    ) -> Result<Response, ClientError> {
+        let session = self.session().unwrap_or_default();
+
+        let scope = match webtoon.scope {
             Scope::Original(_) => "w",
             Scope::Canvas => "c",
         };
-        let webtoon = episode.webtoon.id;
-        let episode = episode.number;
+        let webtoon = webtoon.id;
+
+        let ids = episodes
+            .iter()
+            .map(|episode| format!("{scope}_{webtoon}_{episode}"))
+            .collect::<Vec<_>>()
+            .join(",");
 
         let url = format!(
-            "https://www.webtoons.com/api/v1/like/search/counts?serviceId=LINEWEBTOON&contentIds={scope}_{webtoon}_{episode}"
+            "https://www.webtoons.com/api/v1/like/search/counts?serviceId=LINEWEBTOON&contentIds={ids}"
         );
 
         self.http
             .get(url)
-            .header("Cookie", format!("NEO_SES={session}"))
-            .send()
+            .header("Cookie", self.session_cookie(&session))
+            .send_traced()
             .await
-            .map_err(|err| ClientError::Unexpected(err.into()))
+            .map_err(ClientError::from)
     }
 
     pub(super) async fn like_episode(&self, episode: &Episode) -> Result<(), ClientError> {
@@ -1159,10 +2326,7 @@ impl Client {
             return Err(ClientError::InvalidSession);
         };
 
-        let session = self
-            .session
-            .as_ref()
-            .ok_or(ClientError::NoSessionProvided)?;
+        let session = self.session().ok_or(ClientError::NoSessionProvided)?;
 
         let webtoon = episode.webtoon.id;
         let r#type = episode.webtoon.scope.as_single_letter();
@@ -1188,10 +2352,10 @@ impl Client {
 
             self.http
                 .post(url)
-                .header("Cookie", format!("NEO_SES={session}"))
-                .send()
+                .header("Cookie", self.session_cookie(&session))
+                .send_traced()
                 .await
-                .map_err(|err| ClientError::Unexpected(err.into()))?;
+                .map_err(ClientError::from)?;
         }
 
         Ok(())
@@ -1202,10 +2366,7 @@ impl Client {
             return Err(ClientError::InvalidSession);
         };
 
-        let session = self
-            .session
-            .as_ref()
-            .ok_or(ClientError::NoSessionProvided)?;
+        let session = self.session().ok_or(ClientError::NoSessionProvided)?;
 
         let webtoon = episode.webtoon.id;
         let r#type = episode.webtoon.scope.as_single_letter();
@@ -1232,10 +2393,10 @@ impl Client {
 
             self.http
                 .delete(url)
-                .header("Cookie", format!("NEO_SES={session}"))
-                .send()
+                .header("Cookie", self.session_cookie(&session))
+                .send_traced()
                 .await
-                .map_err(|err| ClientError::Unexpected(err.into()))?;
+                .map_err(ClientError::from)?;
         }
 
         Ok(())
@@ -1247,11 +2408,29 @@ impl Client {
         cursor: Option<Id>,
         stride: u8,
     ) -> Result<Response, ClientError> {
-        let session = self
-            .session
-            .as_ref()
-            .map(|session| session.as_ref())
-            .unwrap_or_default();
+        self.get_posts_for_episode_with_sort(episode, cursor, stride, None)
+            .await
+    }
+
+    pub(super) async fn get_sorted_posts_for_episode(
+        &self,
+        episode: &Episode,
+        cursor: Option<Id>,
+        stride: u8,
+        sort: PostSort,
+    ) -> Result<Response, ClientError> {
+        self.get_posts_for_episode_with_sort(episode, cursor, stride, Some(sort))
+            .await
+    }
+
+    async fn get_posts_for_episode_with_sort(
+        &self,
+        episode: &Episode,
+        cursor: Option<Id>,
+        stride: u8,
+        sort: Option<PostSort>,
+    ) -> Result<Response, ClientError> {
+        let session = self.session().unwrap_or_default();
 
         let scope = match episode.webtoon.scope {
             Scope::Original(_) => "w",
@@ -1264,26 +2443,27 @@ impl Client {
 
         let cursor = cursor.map_or_else(String::new, |id| id.to_string());
 
-        let url = format!("https://www.webtoons.com/p/api/community/v2/posts?pageId={scope}_{webtoon}_{episode}&pinRepresentation=none&prevSize=0&nextSize={stride}&cursor={cursor}&withCursor=true");
+        let mut url = format!("https://www.webtoons.com/p/api/community/v2/posts?pageId={scope}_{webtoon}_{episode}&pinRepresentation=none&prevSize=0&nextSize={stride}&cursor={cursor}&withCursor=true");
+
+        if let Some(sort) = sort {
+            url.push_str("&sort=");
+            url.push_str(sort.as_param());
+        }
 
         self.http
             .get(url)
             .header("Service-Ticket-Id", "epicom")
-            .header("Cookie", format!("NEO_SES={session}"))
-            .send()
+            .header("Cookie", self.session_cookie(&session))
+            .send_traced()
             .await
-            .map_err(|err| ClientError::Unexpected(err.into()))
+            .map_err(ClientError::from)
     }
 
     pub(super) async fn get_upvotes_and_downvotes_for_post(
         &self,
         post: &Post,
     ) -> Result<Response, ClientError> {
-        let session = self
-            .session
-            .as_ref()
-            .map(|session| session.as_ref())
-            .unwrap_or_default();
+        let session = self.session().unwrap_or_default();
 
         let page_id = format!(
             "{}_{}_{}",
@@ -1301,8 +2481,8 @@ impl Client {
             .http
             .get(url)
             .header("Service-Ticket-Id", "epicom")
-            .header("Cookie", format!("NEO_SES={session}"))
-            .send()
+            .header("Cookie", self.session_cookie(&session))
+            .send_traced()
             .await?;
 
         Ok(response)
@@ -1314,11 +2494,7 @@ impl Client {
         cursor: Option<Id>,
         stride: u8,
     ) -> Result<Response, ClientError> {
-        let session = self
-            .session
-            .as_ref()
-            .map(|session| session.as_ref())
-            .unwrap_or_default();
+        let session = self.session().unwrap_or_default();
 
         let post_id = post.id;
 
@@ -1330,8 +2506,24 @@ impl Client {
             .http
             .get(url)
             .header("Service-Ticket-Id", "epicom")
-            .header("Cookie", format!("NEO_SES={session}"))
-            .send()
+            .header("Cookie", self.session_cookie(&session))
+            .send_traced()
+            .await?;
+
+        Ok(response)
+    }
+
+    pub(super) async fn get_post(&self, id: Id) -> Result<Response, ClientError> {
+        let session = self.session().unwrap_or_default();
+
+        let url = format!("https://www.webtoons.com/p/api/community/v2/post/{id}");
+
+        let response = self
+            .http
+            .get(url)
+            .header("Service-Ticket-Id", "epicom")
+            .header("Cookie", self.session_cookie(&session))
+            .send_traced()
             .await?;
 
         Ok(response)
@@ -1339,21 +2531,19 @@ impl Client {
 
     pub(super) async fn post_reply(
         &self,
-        post: &Post,
+        episode: &Episode,
+        parent: Id,
         body: &str,
         is_spoiler: bool,
     ) -> Result<(), ClientError> {
         let page_id = format!(
             "{}_{}_{}",
-            match post.episode.webtoon.scope {
-                Scope::Original(_) => "w",
-                Scope::Canvas => "c",
-            },
-            post.episode.webtoon.id,
-            post.episode.number
+            episode.webtoon.scope.as_single_letter(),
+            episode.webtoon.id,
+            episode.number
         );
 
-        let parent_id = post.id.to_string();
+        let parent_id = parent.to_string();
 
         let spoiler_filter = if is_spoiler { "ON" } else { "OFF" };
         let body = json![
@@ -1368,19 +2558,15 @@ impl Client {
 
         let token = self.get_api_token().await?;
 
-        let session = self
-            .session
-            .as_ref()
-            .map(|session| session.as_ref())
-            .ok_or(ClientError::NoSessionProvided)?;
+        let session = self.session().ok_or(ClientError::NoSessionProvided)?;
 
         self.http
             .post("https://www.webtoons.com/p/api/community/v2/post")
             .json(&body)
             .header("Api-Token", token)
-            .header("Cookie", format!("NEO_SES={session}"))
+            .header("Cookie", self.session_cookie(&session))
             .header("Service-Ticket-Id", "epicom")
-            .send()
+            .send_traced()
             .await?;
 
         Ok(())
@@ -1389,11 +2575,7 @@ impl Client {
     pub(super) async fn delete_post(&self, post: &Post) -> Result<(), PostError> {
         let token = self.get_api_token().await?;
 
-        let session = self
-            .session
-            .as_ref()
-            .map(|session| session.as_ref())
-            .ok_or(ClientError::NoSessionProvided)?;
+        let session = self.session().ok_or(ClientError::NoSessionProvided)?;
 
         self.http
             .delete(format!(
@@ -1401,9 +2583,9 @@ impl Client {
                 post.id
             ))
             .header("Api-Token", token)
-            .header("Cookie", format!("NEO_SES={session}"))
+            .header("Cookie", self.session_cookie(&session))
             .header("Service-Ticket-Id", "epicom")
-            .send()
+            .send_traced()
             .await?;
 
         Ok(())
@@ -1432,24 +2614,41 @@ impl Client {
 
         let token = self.get_api_token().await?;
 
-        let session = self
-            .session
-            .as_ref()
-            .map(|session| session.as_ref())
-            .ok_or(ClientError::NoSessionProvided)?;
+        let session = self.session().ok_or(ClientError::NoSessionProvided)?;
 
         self.http
             .put(url)
             .header("Service-Ticket-Id", "epicom")
             .header("Referer", "https://www.webtoons.com/")
-            .header("Cookie", format!("NEO_SES={session}"))
+            .header("Cookie", self.session_cookie(&session))
             .header("Api-Token", token)
-            .send()
+            .send_traced()
             .await?;
 
         Ok(())
     }
 
+    async fn get_favorites_page(&self, language: Language) -> Result<String, ClientError> {
+        let Some(session) = self.session() else {
+            return Err(ClientError::NoSessionProvided);
+        };
+
+        if !self.has_valid_session().await? {
+            return Err(ClientError::InvalidSession);
+        };
+
+        let response = self
+            .http
+            .get(format!("https://www.webtoons.com/{language}/favorite"))
+            .header("Cookie", self.session_cookie(&session))
+            .send_traced()
+            .await?
+            .text()
+            .await?;
+
+        Ok(response)
+    }
+
     pub(super) async fn get_user_info_for_webtoon(
         &self,
         webtoon: &Webtoon,
@@ -1458,7 +2657,7 @@ impl Client {
             return Err(ClientError::InvalidSession);
         };
 
-        let Some(session) = &self.session else {
+        let Some(session) = self.session() else {
             return Err(ClientError::NoSessionProvided);
         };
 
@@ -1478,8 +2677,8 @@ impl Client {
         let response = self
             .http
             .get(&url)
-            .header("Cookie", format!("NEO_SES={session}"))
-            .send()
+            .header("Cookie", self.session_cookie(&session))
+            .send_traced()
             .await?
             .text()
             .await?;
@@ -1494,16 +2693,16 @@ impl Client {
             return Err(ClientError::InvalidSession);
         };
 
-        let Some(session) = &self.session else {
+        let Some(session) = self.session() else {
             return Err(ClientError::NoSessionProvided);
         };
 
         let response = self
             .http
             .get("https://www.webtoons.com/api/v1/like/react-token")
-            .header("Cookie", format!("NEO_SES={session}"))
+            .header("Cookie", self.session_cookie(&session))
             .header("Referer", "https://www.webtoons.com")
-            .send()
+            .send_traced()
             .await?
             .text()
             .await?;
@@ -1518,15 +2717,15 @@ impl Client {
             return Err(ClientError::InvalidSession);
         };
 
-        let Some(session) = &self.session else {
+        let Some(session) = self.session() else {
             return Err(ClientError::NoSessionProvided);
         };
 
         let response = self
             .http
             .get("https://www.webtoons.com/p/api/community/v1/api-token")
-            .header("Cookie", format!("NEO_SES={session}"))
-            .send()
+            .header("Cookie", self.session_cookie(&session))
+            .send_traced()
             .await?
             .text()
             .await?;
@@ -1629,14 +2828,159 @@ struct ReactResult {
     status_code: Option<u16>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct NewLikesResponse {
-    result: NewLikesResult,
-    success: bool,
+/// Collects the webtoons from the cards of the subscribed list, in the order they are shown.
+fn subscriptions(client: &Client, html: &Html) -> Result<Vec<Webtoon>, ClientError> {
+    let selector =
+        Selector::parse(r#"ul.card_lst a[href*="title_no="]"#) //
+            .expect("`ul.card_lst a[href*=\"title_no=\"]` should be a valid selector");
+
+    let mut webtoons: Vec<Webtoon> = Vec::new();
+
+    for card in html.select(&selector) {
+        let href = card
+            .attr("href")
+            .context("`href` is missing, `a` tag should always have one")?;
+
+        let webtoon = Webtoon::from_url_with_client(href, client)?;
+
+        // A card can have more than one link to the same webtoon, like one on the thumbnail and one on the title.
+        if !webtoons.contains(&webtoon) {
+            webtoons.push(webtoon);
+        }
+    }
+
+    Ok(webtoons)
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct NewLikesResult {
-    count: u32,
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_reject_cookies_that_smuggle_other_cookies() {
+        let build = |name: &str, value: &str| {
+            ClientBuilder::new()
+                .cookies(HashMap::from([(name.to_string(), value.to_string())]))
+                .build()
+        };
+
+        for (name, value) in [
+            ("locale", "x; NEO_SES=evil"),
+            ("locale", "en us"),
+            ("locale", "en\r\nX-Header: 1"),
+            ("locale=x", "en"),
+            ("lo;cale", "en"),
+            ("", "en"),
+        ] {
+            assert!(
+                matches!(build(name, value), Err(ClientError::InvalidCookie(_))),
+                "`{name}={value}` should be rejected"
+            );
+        }
+
+        let client = build("needGDPR", "FALSE=maybe").unwrap();
+        assert_eq!(Some("needGDPR=FALSE=maybe"), client.cookies.as_deref());
+    }
+
+    #[cfg(feature = "cookies")]
+    #[test]
+    fn should_send_refreshed_session_on_next_request() {
+        use reqwest::cookie::CookieStore;
+
+        let path = std::env::temp_dir().join(format!("webtoon-client-jar-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let client = ClientBuilder::new()
+            .with_session("original")
+            .cookie_store(&path)
+            .build()
+            .unwrap();
+
+        let session = client.session().unwrap();
+        assert_eq!("NEO_SES=original", client.session_cookie(&session));
+
+        // Same as what reqwest does with the `Set-Cookie` headers of a response.
+        let refresh = reqwest::header::HeaderValue::from_static("NEO_SES=refreshed; Path=/");
+        client.session_jar.as_ref().unwrap().set_cookies(
+            &mut std::iter::once(&refresh),
+            &"https://www.webtoons.com/en/".parse().unwrap(),
+        );
+
+        let session = client.session().unwrap();
+        assert_eq!("NEO_SES=refreshed", client.session_cookie(&session));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn should_slugify_titles() {
+        assert_eq!("tower-of-god", slugify("Tower of God"));
+        assert_eq!("lore-olympus", slugify("  Lore Olympus!"));
+        assert_eq!(
+            "the-world-after-the-fall",
+            slugify("The World After the Fall")
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_accept_language() {
+        assert!(ClientBuilder::new()
+            .accept_language("en-US")
+            .build()
+            .is_ok());
+        assert!(ClientBuilder::new()
+            .accept_language("en\nUS")
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn should_collect_subscriptions_once_each() {
+        let html = Html::parse_document(
+            r#"<ul class="card_lst">
+<li><a class="card_item" href="https://www.webtoons.com/en/fantasy/tower-of-god/list?title_no=95"><p class="subj">Tower of God</p></a></li>
+<li><a href="https://www.webtoons.com/en/canvas/hello/list?title_no=843910">Hello</a><a href="https://www.webtoons.com/en/canvas/hello/list?title_no=843910">Hello</a></li>
+</ul>
+<a href="https://www.webtoons.com/en/romance/lore-olympus/list?title_no=1320">Recommended</a>"#,
+        );
+
+        let webtoons = subscriptions(&Client::new(), &html).unwrap();
+
+        assert_eq!(
+            vec![(95, Type::Original), (843910, Type::Canvas)],
+            webtoons
+                .iter()
+                .map(|webtoon| (webtoon.id, webtoon.r#type()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_detect_blocked_home_page() {
+        let home = "<html><a class=\"logo\">WEBTOON</a></html>";
+
+        assert_eq!(None, blocked(200, "www.webtoons.com", home));
+        assert!(blocked(403, "www.webtoons.com", home).is_some());
+        assert!(blocked(200, "geo.example.com", home).is_some());
+        assert!(blocked(
+            200,
+            "www.webtoons.com",
+            "<html>Please solve the CAPTCHA</html>"
+        )
+        .is_some());
+        assert!(blocked(200, "www.webtoons.com", "<html></html>").is_some());
+        assert!(blocked(200, "evilwebtoons.com", home).is_some());
+        assert!(blocked(200, "webtoons.com", home).is_none());
+
+        // A normal page quoting a marker, like a title about captchas, is still the home page.
+        assert_eq!(
+            None,
+            blocked(
+                200,
+                "www.webtoons.com",
+                "<html><a class=\"logo\">WEBTOON</a><p>Captcha Girl</p></html>"
+            )
+        );
+    }
 }
@@ -0,0 +1,193 @@
+//! Module containing a cookie jar that persists the `NEO_SES` session cookie to disk.
+
+use super::is_webtoons_host;
+use reqwest::{cookie::CookieStore, header::HeaderValue};
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+use url::Url;
+
+const SESSION_COOKIE: &str = "NEO_SES";
+
+/// Keeps track of the `NEO_SES` cookie, writing it to disk whenever the server sends a new one.
+///
+/// Only the session cookie is stored, as it's the only cookie the client relies on. The file contains just the raw
+/// session value.
+///
+/// The session is only sent to and taken from `webtoons.com` and its subdomains, so that requests to other hosts, like
+/// the image CDN, never see it.
+#[derive(Debug)]
+pub(super) struct SessionJar {
+    path: PathBuf,
+    session: RwLock<Option<Arc<str>>>,
+    /// Extra cookies from `ClientBuilder::cookies`, sent along with the session but never saved.
+    cookies: Option<Arc<str>>,
+}
+
+impl SessionJar {
+    /// Loads the session from `path`, if the file exists.
+    pub(super) fn load(path: &Path) -> std::io::Result<Self> {
+        let session = match std::fs::read_to_string(path) {
+            Ok(contents) => Some(contents.trim())
+                .filter(|session| !session.is_empty())
+                .map(Arc::from),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => return Err(err),
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            session: RwLock::new(session),
+            cookies: None,
+        })
+    }
+
+    pub(super) fn with_cookies(self, cookies: Option<Arc<str>>) -> Self {
+        Self { cookies, ..self }
+    }
+
+    pub(super) fn session(&self) -> Option<Arc<str>> {
+        self.session
+            .read()
+            .expect("session lock should not be poisoned")
+            .clone()
+    }
+
+    /// Sets the session, saving it to disk if it changed.
+    pub(super) fn set_session(&self, session: Arc<str>) -> std::io::Result<()> {
+        let mut guard = self
+            .session
+            .write()
+            .expect("session lock should not be poisoned");
+
+        if guard.as_deref() == Some(session.as_ref()) {
+            return Ok(());
+        }
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(&self.path, session.as_bytes())?;
+
+        *guard = Some(session);
+
+        Ok(())
+    }
+}
+
+impl CookieStore for SessionJar {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
+        if !url.host_str().is_some_and(is_webtoons_host) {
+            return;
+        }
+
+        for header in cookie_headers {
+            let Some(session) = header.to_str().ok().and_then(session_from_set_cookie) else {
+                continue;
+            };
+
+            // NOTE: There is no way to surface an error from here. Failing to save only means the refreshed session
+            // won't be there on the next run, which is the same as not having a jar.
+            let _ = self.set_session(Arc::from(session));
+        }
+    }
+
+    fn cookies(&self, url: &Url) -> Option<HeaderValue> {
+        if !url.host_str().is_some_and(is_webtoons_host) {
+            return None;
+        }
+
+        let cookie = match (self.session(), &self.cookies) {
+            (Some(session), Some(cookies)) => format!("{SESSION_COOKIE}={session}; {cookies}"),
+            (Some(session), None) => format!("{SESSION_COOKIE}={session}"),
+            (None, Some(cookies)) => cookies.to_string(),
+            (None, None) => return None,
+        };
+
+        HeaderValue::from_str(&cookie).ok()
+    }
+}
+
+/// Gets the session from a `Set-Cookie` header value, e.g. `NEO_SES="abc"; Path=/; Domain=.webtoons.com`.
+///
+/// Returns `None` for other cookies, and for an empty session, which is how the server clears it.
+fn session_from_set_cookie(header: &str) -> Option<&str> {
+    let (name, value) = header.split(';').next()?.split_once('=')?;
+
+    if name.trim() != SESSION_COOKIE {
+        return None;
+    }
+
+    let value = value.trim().trim_matches('"');
+
+    (!value.is_empty()).then_some(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_get_session_from_set_cookie() {
+        assert_eq!(
+            session_from_set_cookie("NEO_SES=\"abc123\"; Path=/; Domain=.webtoons.com"),
+            Some("abc123")
+        );
+        assert_eq!(session_from_set_cookie("NEO_SES=abc123"), Some("abc123"));
+        assert_eq!(session_from_set_cookie("NEO_SES=; Max-Age=0"), None);
+        assert_eq!(session_from_set_cookie("wtu=abc123; Path=/"), None);
+    }
+
+    #[test]
+    fn should_send_extra_cookies_with_or_without_session() {
+        let path = std::env::temp_dir().join(format!("webtoon-jar-extra-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let url = Url::parse("https://www.webtoons.com/").unwrap();
+        let jar = SessionJar::load(&path)
+            .unwrap()
+            .with_cookies(Some(Arc::from("locale=en; needGDPR=FALSE")));
+
+        assert_eq!(
+            jar.cookies(&url).unwrap(),
+            HeaderValue::from_static("locale=en; needGDPR=FALSE")
+        );
+
+        jar.set_session(Arc::from("abc123")).unwrap();
+        assert_eq!(
+            jar.cookies(&url).unwrap(),
+            HeaderValue::from_static("NEO_SES=abc123; locale=en; needGDPR=FALSE")
+        );
+
+        let cdn = Url::parse("https://swebtoon-phinf.pstatic.net/panel.jpg").unwrap();
+        assert_eq!(jar.cookies(&cdn), None);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn should_persist_refreshed_session() {
+        let path = std::env::temp_dir().join(format!("webtoon-jar-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let jar = SessionJar::load(&path).unwrap();
+        assert_eq!(jar.session(), None);
+
+        let header = HeaderValue::from_static("NEO_SES=refreshed; Path=/");
+
+        let other = Url::parse("https://evilwebtoons.com/").unwrap();
+        jar.set_cookies(&mut std::iter::once(&header), &other);
+        assert_eq!(jar.session(), None);
+
+        let url = Url::parse("https://www.webtoons.com/").unwrap();
+        jar.set_cookies(&mut std::iter::once(&header), &url);
+
+        let jar = SessionJar::load(&path).unwrap();
+        assert_eq!(jar.session().as_deref(), Some("refreshed"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,29 @@
+//! Module containing the resolver used by [`Client`](super::Client), so failed lookups can be told apart from other connect errors.
+
+use crate::platform::webtoons::errors::ResolveError;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+
+/// Resolves hosts with the system resolver, same as `reqwest` would.
+///
+/// The difference is that a failed lookup is returned as a [`ResolveError`], which `reqwest` passes along untouched,
+/// leaving something to downcast to when classifying a [`ClientError::Dns`](crate::platform::webtoons::errors::ClientError::Dns).
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Resolver;
+
+impl Resolve for Resolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            // The port is replaced by the one from the url once connecting.
+            let addrs = tokio::net::lookup_host((name.as_str(), 0))
+                .await
+                // The iterator borrows `name`, so the addresses are collected to outlive it.
+                .map(Iterator::collect::<Vec<_>>)
+                .map_err(|source| ResolveError {
+                    host: name.as_str().to_string(),
+                    source,
+                })?;
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
@@ -1,4 +1,5 @@
 use serde::Deserialize;
+use std::collections::BTreeMap;
 
 #[derive(Deserialize, Debug)]
 pub struct Likes {
@@ -13,12 +14,65 @@ pub struct Contents {
 
 #[derive(Deserialize, Debug)]
 pub struct Reaction {
+    #[serde(default, alias = "contentId")]
+    pub content_id: Option<String>,
     #[serde(alias = "reactions")]
     pub reactions: Vec<Count>,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct Count {
+    #[serde(default, alias = "reactionType")]
+    pub reaction_type: String,
     #[serde(alias = "count")]
     pub count: u32,
 }
+
+impl Reaction {
+    /// Parses the episode number out of a content id like `w_95_1`.
+    pub fn episode(&self) -> Option<u16> {
+        self.content_id.as_deref()?.rsplit('_').next()?.parse().ok()
+    }
+
+    pub fn count(&self) -> u32 {
+        self.reactions
+            .first()
+            .map(|likes| likes.count)
+            .unwrap_or_default()
+    }
+
+    /// Returns the count of every reaction type, like `like`, keyed by its id.
+    pub fn counts(&self) -> BTreeMap<String, u32> {
+        let mut counts = BTreeMap::new();
+
+        for reaction in &self.reactions {
+            *counts.entry(reaction.reaction_type.clone()).or_default() += reaction.count;
+        }
+
+        counts
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_count_each_reaction_type() {
+        let likes: Likes = serde_json::from_str(
+            r#"{"result":{"contents":[{"contentId":"w_95_1","reactions":[
+{"reactionType":"like","count":120,"isReacted":false},
+{"reactionType":"laugh","count":7,"isReacted":true}]}]}}"#,
+        )
+        .unwrap();
+
+        let reaction = &likes.result.contents[0];
+
+        assert_eq!(120, reaction.count());
+        assert_eq!(
+            BTreeMap::from([("laugh".to_string(), 7), ("like".to_string(), 120)]),
+            reaction.counts()
+        );
+    }
+}
@@ -10,6 +10,14 @@ pub struct PostsResult {
     pub status: String,
 }
 
+#[allow(dead_code)]
+#[derive(Deserialize)]
+pub struct PostResult {
+    pub result: Post,
+    // "success"
+    pub status: String,
+}
+
 #[allow(dead_code)]
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -119,7 +127,11 @@ pub struct BodyFormat {
 
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ProfileImage {}
+pub struct ProfileImage {
+    // Missing when the user never set an avatar: `"profileImage": {}`
+    #[serde(default)]
+    pub url: Option<String>,
+}
 
 #[allow(dead_code)]
 #[derive(Deserialize)]
@@ -353,7 +365,7 @@ pub mod id {
             let id = s
                 .split(':')
                 // get `0-w_95_1-1d-z`
-                .last()
+                .next_back()
                 .ok_or_else(|| ParseIdError::InvalidFormat {
                     id: s.to_owned(),
                     context: "there was no right-hand part after splitting on `:`".to_string(),
@@ -443,6 +455,58 @@ pub mod id {
         }
     }
 
+    impl Id {
+        /// Returns `true` if the id is for a post on the given `{scope}_{webtoon}_{episode}` page.
+        pub(crate) fn is_on_page(&self, scope: &str, webtoon: u32, episode: u16) -> bool {
+            self.scope == scope && self.webtoon == webtoon && self.episode == episode
+        }
+
+        /// Returns `true` if the id is for a reply rather than a top-level comment.
+        pub(crate) fn is_reply(&self) -> bool {
+            self.reply.is_some()
+        }
+
+        /// Returns how many ids apart `other` is from this one, positive when `other` was posted later.
+        ///
+        /// As ids are handed out in order, a distance greater than `1` between two neighboring visible posts is the
+        /// number of posts in between that were deleted, plus one.
+        ///
+        /// Top-level comments and replies are counted separately: comments are numbered across the episode, and
+        /// replies are numbered under the comment they reply to.
+        ///
+        /// ### Returns
+        ///
+        /// - `Some(distance)`: For two top-level comments on the same episode, or two replies to the same comment.
+        /// - `None`: If the ids are for different episodes or webtoons, for replies to different comments, or for a
+        ///   comment and a reply.
+        ///
+        /// ### Example
+        ///
+        /// ```
+        /// # use webtoon::platform::webtoons::webtoon::episode::posts::Id;
+        /// let first: Id = "GW-epicom:0-w_95_1-1d".parse()?;
+        /// let later: Id = "GW-epicom:0-w_95_1-1h".parse()?;
+        ///
+        /// assert_eq!(Some(4), first.distance(&later));
+        /// assert_eq!(Some(-4), later.distance(&first));
+        /// # Ok::<(), Box<dyn std::error::Error>>(())
+        /// ```
+        #[must_use]
+        pub fn distance(&self, other: &Self) -> Option<i64> {
+            if !other.is_on_page(self.scope, self.webtoon, self.episode) {
+                return None;
+            }
+
+            let (from, to) = match (self.reply, other.reply) {
+                (None, None) => (self.post, other.post),
+                (Some(from), Some(to)) if self.post == other.post => (from, to),
+                _ => return None,
+            };
+
+            Some(i64::from(to.get()) - i64::from(from.get()))
+        }
+    }
+
     impl Display for Id {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             if let Some(reply) = &self.reply {
@@ -500,11 +564,11 @@ pub mod id {
         }
     }
 
+    #[allow(
+        clippy::non_canonical_partial_ord_impl,
+        reason = "`Id` ordering is only meaningful for the same webtoon on the same episode"
+    )]
     impl PartialOrd for Id {
-        #[allow(
-            clippy::non_canonical_partial_ord_impl,
-            reason = "`Id` ordering is only meaningful for the same webtoon on the same episode"
-        )]
         fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
             // If not a post on the same webtoons' episode then return `None`.
             // Cannot add `self.tag != other.tag` as its still unknown how this number increments, but given that the other
@@ -534,6 +598,48 @@ pub mod id {
     mod test {
         use super::*;
 
+        #[test]
+        fn should_match_page() {
+            let id = Id::from_str("GW-epicom:0-c_843910_1-1d").unwrap();
+            let reply = Id::from_str("GW-epicom:0-c_843910_1-1d-1").unwrap();
+
+            assert!(id.is_on_page("c", 843910, 1));
+            assert!(!id.is_on_page("w", 843910, 1));
+            assert!(!id.is_on_page("c", 843910, 2));
+            assert!(!id.is_reply());
+            assert!(reply.is_reply());
+        }
+
+        #[test]
+        fn should_measure_distance_within_the_same_sequence() {
+            let post = |id: &str| Id::from_str(id).unwrap();
+
+            pretty_assertions::assert_eq!(
+                Some(4),
+                post("GW-epicom:0-w_95_1-1d").distance(&post("GW-epicom:0-w_95_1-1h"))
+            );
+            pretty_assertions::assert_eq!(
+                Some(0),
+                post("GW-epicom:0-w_95_1-1d").distance(&post("GW-epicom:0-w_95_1-1d"))
+            );
+            pretty_assertions::assert_eq!(
+                Some(-2),
+                post("GW-epicom:0-w_95_1-1d-3").distance(&post("GW-epicom:0-w_95_1-1d-1"))
+            );
+            pretty_assertions::assert_eq!(
+                None,
+                post("GW-epicom:0-w_95_1-1d-3").distance(&post("GW-epicom:0-w_95_1-1e-1"))
+            );
+            pretty_assertions::assert_eq!(
+                None,
+                post("GW-epicom:0-w_95_1-1d").distance(&post("GW-epicom:0-w_95_1-1d-1"))
+            );
+            pretty_assertions::assert_eq!(
+                None,
+                post("GW-epicom:0-w_95_1-1d").distance(&post("GW-epicom:0-w_95_2-1h"))
+            );
+        }
+
         #[test]
         fn should_be_equal_str() {
             let id = Id {
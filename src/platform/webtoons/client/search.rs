@@ -1,8 +1,12 @@
 //! Module for webtoons.com search API.
 
+use anyhow::Context;
 use serde::{Deserialize, Serialize};
 
-use crate::platform::webtoons::{errors::WebtoonError, Type, Webtoon};
+use crate::platform::webtoons::{
+    errors::{SearchError, WebtoonError},
+    Type, Webtoon,
+};
 
 use super::Client;
 
@@ -14,9 +18,36 @@ pub struct Item {
     pub(super) title: String,
     pub(super) thumbnail: String,
     pub(super) creator: String,
+    pub(super) authors: Vec<String>,
+    pub(super) rank: usize,
 }
 
 impl Item {
+    pub(super) fn new(client: &Client, r#type: Type, data: Data) -> Result<Self, SearchError> {
+        let id: u32 = data
+            .content_id
+            .parse()
+            .context("Failed to parse webtoon id to u32")?;
+
+        let mut authors = vec![data.extra.writer.nickname.clone()];
+
+        if data.extra.illustrator.nickname != data.extra.writer.nickname {
+            authors.push(data.extra.illustrator.nickname);
+        }
+
+        Ok(Self {
+            client: client.clone(),
+            id,
+            r#type,
+            title: data.name,
+            thumbnail: format!("https://swebtoon-phinf.pstatic.net{}", data.thumbnail.path),
+            creator: data.extra.writer.nickname,
+            authors,
+            // Set once all results are in.
+            rank: 0,
+        })
+    }
+
     /// Returns the id of the webtoon.
     #[must_use]
     pub fn id(&self) -> u32 {
@@ -47,6 +78,21 @@ impl Item {
         &self.creator
     }
 
+    /// Returns the names of everyone credited on the webtoon: the writer, then the illustrator if they're someone else.
+    #[must_use]
+    pub fn authors(&self) -> &[String] {
+        &self.authors
+    }
+
+    /// Returns where the search ranked this webtoon among the results of the same [`Type`], starting at `1` for the
+    /// best match.
+    ///
+    /// The search API gives no score, only an order, so this is the closest there is to a relevance.
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
     /// Turns a search result into a [`Webtoon`] so that interaction can be done on it.
     ///
     /// Rather than having a search result in a [`Webtoon`], there is information that is not easily shared or
@@ -131,3 +177,40 @@ pub(super) struct Extra {
     pub unsuitable_for_children: Option<bool>,
     pub writer: Writer,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_credit_illustrator_only_when_different() {
+        let data = |writer: &str, illustrator: &str| -> Data {
+            serde_json::from_value(serde_json::json!({
+                "contentId": "95",
+                "contentSubType": "WEBTOON",
+                "extra": {
+                    "illustrator": { "nickname": illustrator },
+                    "writer": { "nickname": writer },
+                },
+                "name": "Tower of God",
+                "serviceType": "GW",
+                "thumbnail": { "domain": "", "path": "/thumb.jpg" },
+            }))
+            .unwrap()
+        };
+
+        let client = Client::new();
+
+        let item = Item::new(&client, Type::Original, data("SIU", "SIU")).unwrap();
+        assert_eq!(["SIU".to_string()], item.authors());
+
+        let item = Item::new(&client, Type::Original, data("Writer", "Artist")).unwrap();
+        assert_eq!(["Writer".to_string(), "Artist".to_string()], item.authors());
+        assert_eq!("Writer", item.creator());
+        assert_eq!(
+            "https://swebtoon-phinf.pstatic.net/thumb.jpg",
+            item.thumbnail()
+        );
+    }
+}
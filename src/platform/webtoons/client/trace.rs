@@ -0,0 +1,50 @@
+//! Module containing the one place requests are sent from, so they can be traced.
+
+use reqwest::{RequestBuilder, Response};
+
+/// Sends a request, recording it with `tracing` when the `tracing` feature is enabled.
+pub(crate) trait Traced {
+    /// Same as [`RequestBuilder::send`].
+    ///
+    /// With the `tracing` feature, each request is sent inside a `DEBUG` level `request` span with the `method` and
+    /// `url`, and ends with an event holding either the `status` or the `error`, along with `elapsed_ms`. Headers are
+    /// never recorded, as they hold the session.
+    async fn send_traced(self) -> reqwest::Result<Response>;
+}
+
+impl Traced for RequestBuilder {
+    #[cfg(not(feature = "tracing"))]
+    async fn send_traced(self) -> reqwest::Result<Response> {
+        self.send().await
+    }
+
+    #[cfg(feature = "tracing")]
+    async fn send_traced(self) -> reqwest::Result<Response> {
+        use std::time::Instant;
+        use tracing::Instrument;
+
+        let (client, request) = self.build_split();
+        let request = request?;
+
+        let span = tracing::debug_span!(
+            "request",
+            method = %request.method(),
+            url = %request.url(),
+        );
+
+        let start = Instant::now();
+        let result = client.execute(request).instrument(span.clone()).await;
+        let elapsed_ms = start.elapsed().as_millis();
+
+        match &result {
+            Ok(response) => {
+                tracing::debug!(parent: &span, status = response.status().as_u16(), elapsed_ms, "response");
+            }
+            Err(error) => {
+                tracing::debug!(parent: &span, %error, elapsed_ms, "request failed");
+            }
+        }
+
+        result
+    }
+}
@@ -6,7 +6,8 @@ use scraper::{Html, Selector};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-use super::{errors::CreatorError, Client, Language, Type, Webtoon};
+use super::client::trace::Traced;
+use super::{errors::CreatorError, meta::Genre, Client, Language, Type, Webtoon};
 
 /// Represents a creator of a webtoon.
 ///
@@ -33,6 +34,42 @@ impl Debug for Creator {
     }
 }
 
+/// The part a [`Creator`] has on a webtoon.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// Wrote the story.
+    Writer,
+    /// Drew the art.
+    Artist,
+    /// Both wrote and drew it.
+    WriterAndArtist,
+    /// Wrote the original work, like a novel, that the webtoon adapts.
+    OriginalAuthor,
+    /// Assisted with the art.
+    Assistant,
+    /// The role wasn't given, or isn't one of the above.
+    ///
+    /// Until the field webtoons.com uses for roles is confirmed, this is what every title is expected to have. See
+    /// [`Creator::webtoons_with_roles`].
+    Unknown,
+}
+
+impl Role {
+    // NOTE: These tokens are guesses at what the creator titles api uses, as no response seen so far has had a role in
+    // it. Anything else is `Unknown`.
+    fn from_api(role: &str) -> Self {
+        match role.to_ascii_uppercase().as_str() {
+            "WRITER" | "STORY" => Self::Writer,
+            "ARTIST" | "ILLUSTRATOR" | "ART" => Self::Artist,
+            "CREATOR" | "WRITER_ARTIST" | "STORY_ART" => Self::WriterAndArtist,
+            "ORIGINAL" | "ORIGINAL_AUTHOR" => Self::OriginalAuthor,
+            "ASSISTANT" => Self::Assistant,
+            _ => Self::Unknown,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct Page {
     pub username: String,
@@ -92,72 +129,165 @@ impl Creator {
     ///
     /// Will error if scrape encountered an unexpected html shape, or if network request encounter issues.
     pub async fn webtoons(&self) -> Result<Option<Vec<Webtoon>>, CreatorError> {
-        let Some(profile) = self
-            .profile
-            .as_deref()
-            // Profiles can be prefixed with `_` but the url needs it trimmed to work.
-            .map(|profile| profile.trim_start_matches('_'))
-        else {
+        let Some(titles) = self.titles().await? else {
+            return Ok(None);
+        };
+
+        let mut webtoons = Vec::with_capacity(titles.len());
+
+        for title in titles {
+            webtoons.push(self.webtoon(&title).await?);
+        }
+
+        Ok(Some(webtoons))
+    }
+
+    /// Same as [`Creator::webtoons`], but with the [`Role`] the creator has on each webtoon.
+    ///
+    /// Useful for credits, where it matters whether the creator wrote, drew, or assisted on a title.
+    ///
+    /// # Returns
+    ///
+    /// `None` in the same cases as [`Creator::webtoons`]. A role that the creator's entry doesn't list, or that isn't
+    /// known, is [`Role::Unknown`].
+    ///
+    /// The field and values the role is read from (`role` or `authorType`, with values like `WRITER` or `ARTIST`) are
+    /// not confirmed by any captured response from webtoons.com. Until they are, expect [`Role::Unknown`] for every
+    /// title, and don't rely on a role being known.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Creator::webtoons`].
+    pub async fn webtoons_with_roles(&self) -> Result<Option<Vec<(Webtoon, Role)>>, CreatorError> {
+        let Some(titles) = self.titles().await? else {
             return Ok(None);
         };
 
+        let mut webtoons = Vec::with_capacity(titles.len());
+
+        for title in titles {
+            let role = title
+                .authors
+                .iter()
+                .find(|author| author.nickname.eq_ignore_ascii_case(&self.username))
+                .and_then(|author| author.role.as_deref())
+                .map_or(Role::Unknown, Role::from_api);
+
+            webtoons.push((self.webtoon(&title).await?, role));
+        }
+
+        Ok(Some(webtoons))
+    }
+
+    async fn webtoon(&self, title: &api::Titles) -> Result<Webtoon, CreatorError> {
+        let id = title
+            .id
+            .parse::<u32>()
+            .context("failed to parse webtoon id to number")?;
+
+        let r#type = title.r#type.parse::<Type>()?;
+
+        let mut webtoon = Webtoon::new_with_client(id, r#type, &self.client).await?;
+
+        let genres = genres(&title.genres);
+
+        if !genres.is_empty() {
+            webtoon.genres = Some(Arc::from(genres));
+        }
+
+        Ok(webtoon)
+    }
+
+    /// Gets the titles of the creator from the community API, or `None` if the creator has no profile.
+    async fn titles(&self) -> Result<Option<Vec<api::Titles>>, CreatorError> {
+        let Some(profile) = self.trimmed_profile() else {
+            return Ok(None);
+        };
+
+        if let Some(response) = self.titles_for(profile).await? {
+            return Ok(Some(response.result.titles));
+        }
+
+        // Some profiles aren't accepted by the API, but the creator id from the profile page is.
+        let id = self
+            .page_id()
+            .await?
+            .context("failed to find creator profile property on creator page html")?;
+
+        let response = self
+            .titles_for(&id)
+            .await?
+            .context("creator titles api didn't accept the creator id from the profile page")?;
+
+        Ok(Some(response.result.titles))
+    }
+
+    /// Profiles can be prefixed with `_` but the api and urls need it trimmed to work.
+    fn trimmed_profile(&self) -> Option<&str> {
+        self.profile
+            .as_deref()
+            .map(|profile| profile.trim_start_matches('_'))
+    }
+
+    /// Requests the titles for `profile`, which can also be a creator id, returning `None` if the response isn't the
+    /// expected JSON.
+    async fn titles_for(&self, profile: &str) -> Result<Option<api::Response>, CreatorError> {
         let language = self.language.as_str_caps();
 
         let url = format!("https://www.webtoons.com/p/community/api/v1/creator/{profile}/titles?language={language}");
 
-        let response = if let Ok(response) = self
+        let response = self
             .client
-            .http
-            .get(url)
-            .send()
+            .get(&url)
+            .send_traced()
             .await?
             .json::<api::Response>()
             .await
-        {
-            response
-        } else {
-            let mut lock = self.page.lock().await;
+            .ok();
 
-            if lock.is_none() {
-                let Some(profile) = self.profile.as_deref() else {
-                    return Ok(None);
-                };
+        Ok(response)
+    }
 
-                *lock = page(self.language, profile, &self.client).await?;
-            }
+    /// Returns the creator id embedded in the profile page, scraping the page if it isn't cached yet.
+    async fn page_id(&self) -> Result<Option<String>, CreatorError> {
+        let mut lock = self.page.lock().await;
 
-            let profile = lock
-                .as_ref()
-                .map(|page| page.id.clone())
-                .context("failed to find creator profile property on creator page html")?;
+        if lock.is_none() {
+            let Some(profile) = self.profile.as_deref() else {
+                return Ok(None);
+            };
 
-            drop(lock);
+            *lock = page(self.language, profile, &self.client).await?;
+        }
 
-            let url = format!("https://www.webtoons.com/p/community/api/v1/creator/{profile}/titles?language={language}");
+        Ok(lock.as_ref().map(|page| page.id.clone()))
+    }
 
-            self.client
-                .http
-                .get(url)
-                .send()
-                .await?
-                .json::<api::Response>()
-                .await?
+    /// Returns the creator's id, which webtoons.com uses in place of the profile in some places.
+    ///
+    /// The community titles API is asked first, as it gives the id as data. Only if that doesn't have it, like for a
+    /// creator without any public webtoons, is the id taken from the script of the profile page, which is more likely
+    /// to break when the page changes.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the creator has no profile, or the profile page doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Will error if the network request encounters issues, or the profile page had an unexpected shape.
+    pub async fn id(&self) -> Result<Option<String>, CreatorError> {
+        let Some(profile) = self.trimmed_profile() else {
+            return Ok(None);
         };
 
-        let mut webtoons = Vec::with_capacity(response.result.total_count);
-
-        for webtoon in response.result.titles {
-            let id = webtoon
-                .id
-                .parse::<u32>()
-                .context("failed to parse webtoon id to number")?;
-
-            let r#type = webtoon.r#type.parse::<Type>()?;
-
-            webtoons.push(Webtoon::new_with_client(id, r#type, &self.client).await?);
+        if let Some(response) = self.titles_for(profile).await? {
+            if let Some(id) = id_from_titles(&response.result.titles, &self.username) {
+                return Ok(Some(id));
+            }
         }
 
-        Ok(Some(webtoons))
+        self.page_id().await
     }
 
     /// Returns if creator has a Patreon linked to their account.
@@ -239,7 +369,7 @@ pub(super) async fn page(
 
     Ok(Some(Page {
         username: username(&html)?,
-        followers: followers(&html)?,
+        followers: followers(&html, language)?,
         has_patreon: has_patreon(&html),
         id: id(&html)?,
     }))
@@ -266,7 +396,7 @@ fn username(html: &Html) -> Result<String, CreatorError> {
     )))
 }
 
-fn followers(html: &Html) -> Result<u32, CreatorError> {
+fn followers(html: &Html, language: Language) -> Result<u32, CreatorError> {
     let selector = Selector::parse("span").expect("`span` should be a valid selector");
 
     // The same class name is used for series count as well. To get the followers, we need the second instance,
@@ -277,13 +407,14 @@ fn followers(html: &Html) -> Result<u32, CreatorError> {
         if let Some(class) = element.value().attr("class") {
             if class.starts_with("CreatorBriefMetric_count") {
                 if encountered_class {
-                    return Ok(element
+                    let count = element
                         .text()
                         .next()
-                        .context("follower count element was empty")?
-                        .replace(',', "")
-                        .parse()
-                        .context("follower count was not a number")?);
+                        .context("follower count element was empty")?;
+
+                    return Ok(parse_count(count, language).with_context(|| {
+                        format!("follower count `{count}` was not a number for `{language:?}`")
+                    })?);
                 }
 
                 encountered_class = true;
@@ -296,6 +427,58 @@ fn followers(html: &Html) -> Result<u32, CreatorError> {
     )))
 }
 
+/// Parses a count like `1,234`, `1.234`, or `1 234`, depending on which thousands separator `language` uses.
+///
+/// Only the separator of `language` is stripped, so a count in an unexpected format fails rather than silently
+/// parsing into the wrong number.
+fn parse_count(count: &str, language: Language) -> Option<u32> {
+    let separator = match language {
+        Language::En | Language::Th | Language::Zh => Some(','),
+        Language::Es | Language::Id | Language::De => Some('.'),
+        // Uses (non-breaking) spaces, which are handled below for every language.
+        Language::Fr => None,
+    };
+
+    count
+        .trim()
+        .replace("&nbsp;", "")
+        .chars()
+        .filter(|ch| Some(*ch) != separator && !ch.is_whitespace())
+        .collect::<String>()
+        .parse()
+        .ok()
+}
+
+/// Finds the id of the creator with `username` among the authors of their titles.
+fn id_from_titles(titles: &[api::Titles], username: &str) -> Option<String> {
+    titles
+        .iter()
+        .flat_map(|title| &title.authors)
+        .filter(|author| author.nickname.eq_ignore_ascii_case(username))
+        .find_map(|author| author.creator_id.clone())
+}
+
+/// Maps the genre codes of a title into genres, ordered like those scraped from the landing page: the primary genre
+/// first, then the rest by slug. Unknown codes are dropped.
+fn genres(codes: &[String]) -> Vec<Genre> {
+    let mut genres: Vec<Genre> = codes
+        .iter()
+        .filter_map(|code| Genre::from_api_slug(code))
+        .collect();
+
+    if genres.is_empty() {
+        return genres;
+    }
+
+    let primary = genres.remove(0);
+    genres.retain(|genre| *genre != primary);
+    genres.sort_unstable_by_key(|genre| genre.as_slug());
+    genres.dedup();
+    genres.insert(0, primary);
+
+    genres
+}
+
 fn id(html: &Html) -> Result<String, CreatorError> {
     let selector = Selector::parse("script").expect("`script` should be a valid selector");
 
@@ -400,5 +583,96 @@ mod api {
     #[derive(Deserialize)]
     pub(super) struct Authors {
         pub nickname: String,
+        #[serde(default, rename = "creatorId", alias = "id")]
+        pub creator_id: Option<String>,
+        // NOTE: Unconfirmed, no captured response has had either field. See `Role::from_api`.
+        #[serde(default, alias = "authorType")]
+        pub role: Option<String>,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn profile(followers: &str) -> Html {
+        Html::parse_document(&format!(
+            r#"<div><span class="CreatorBriefMetric_count__abc12">3</span><span class="CreatorBriefMetric_count__abc12">{followers}</span></div>"#
+        ))
+    }
+
+    #[test]
+    fn should_parse_followers_per_locale() {
+        let fixtures = [
+            (Language::En, "1,234,567"),
+            (Language::Th, "1,234,567"),
+            (Language::Es, "1.234.567"),
+            (Language::Id, "1.234.567"),
+            (Language::De, "1.234.567"),
+            (Language::Fr, "1 234 567"),
+            (Language::Fr, "1\u{a0}234\u{a0}567"),
+            (Language::Fr, "1&nbsp;234&nbsp;567"),
+        ];
+
+        for (language, followers) in fixtures {
+            assert_eq!(
+                1_234_567,
+                super::followers(&profile(followers), language).unwrap(),
+                "{language:?}: {followers}"
+            );
+        }
+    }
+
+    #[test]
+    fn should_map_api_roles() {
+        assert_eq!(Role::Writer, Role::from_api("WRITER"));
+        assert_eq!(Role::Artist, Role::from_api("illustrator"));
+        assert_eq!(Role::WriterAndArtist, Role::from_api("CREATOR"));
+        assert_eq!(Role::Assistant, Role::from_api("ASSISTANT"));
+        assert_eq!(Role::Unknown, Role::from_api("LETTERER"));
+
+        let authors: Vec<api::Authors> = serde_json::from_str(
+            r#"[{"nickname":"SIU","authorType":"WRITER"},{"nickname":"Someone"}]"#,
+        )
+        .unwrap();
+        assert_eq!(Some("WRITER"), authors[0].role.as_deref());
+        assert_eq!(None, authors[1].role);
+    }
+
+    #[test]
+    fn should_resolve_id_from_titles_then_profile_script() {
+        let titles: Vec<api::Titles> = serde_json::from_str(
+            r#"[{"id":"843910","subject":"Testing Service","authors":[{"nickname":"Other"},{"nickname":"Testy","creatorId":"n5z4d"}],"genres":[],"grade":"CHALLENGE","thumbnailUrl":"","recentEpisodeRegisteredAt":0,"titleRegisteredAt":0}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(Some("n5z4d".to_string()), id_from_titles(&titles, "testy"));
+        assert_eq!(None, id_from_titles(&titles, "Other"));
+        assert_eq!(None, id_from_titles(&[], "Testy"));
+
+        let html = Html::parse_document(
+            r#"<html><body><script>self.__next_f.push([1,"{\"creatorId\":\"n5z4d\",\"nickname\":\"Testy\"}"])</script></body></html>"#,
+        );
+
+        assert_eq!("n5z4d", id(&html).unwrap());
+    }
+
+    #[test]
+    fn should_map_title_genres() {
+        let codes = ["FANTASY", "SLICE_OF_LIFE", "UNKNOWN", "ACTION", "FANTASY"].map(String::from);
+
+        assert_eq!(
+            vec![Genre::Fantasy, Genre::Action, Genre::SliceOfLife],
+            genres(&codes)
+        );
+        assert!(genres(&[]).is_empty());
+    }
+
+    #[test]
+    fn should_reject_separator_from_other_locale() {
+        assert!(parse_count("1.234", Language::En).is_none());
+        assert!(parse_count("1,234", Language::Es).is_none());
+        assert_eq!(Some(999), parse_count("999", Language::De));
     }
 }
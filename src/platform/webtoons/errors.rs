@@ -2,6 +2,9 @@
 
 use thiserror::Error;
 
+use super::webtoon::episode::Viewer;
+use super::Type;
+
 #[allow(missing_docs)]
 #[non_exhaustive]
 #[derive(Debug, Error)]
@@ -41,16 +44,87 @@ pub enum ClientError {
     InvalidSession,
     #[error("Rate limit was exceeded")]
     RateLimitExceeded(u64),
+    #[error("Failed to resolve the host: {0}")]
+    Dns(#[source] reqwest::Error),
+    #[error("TLS handshake failed: {0}")]
+    Tls(#[source] reqwest::Error),
+    #[error("Failed to connect: {0}")]
+    Connect(#[source] reqwest::Error),
+    #[error("Timed out while connecting: {0}")]
+    ConnectTimeout(#[source] reqwest::Error),
+    #[error("Timed out waiting for a response: {0}")]
+    ReadTimeout(#[source] reqwest::Error),
+    #[error("Cookie `{0}` has a name or value that can't be sent as a single cookie")]
+    InvalidCookie(String),
+    #[error("Site looks to be blocking this connection: {0}")]
+    Blocked(String),
+    #[error("Operation was cancelled")]
+    Cancelled,
     #[error(transparent)]
     Unexpected(#[from] anyhow::Error),
 }
 
 impl From<reqwest::Error> for ClientError {
     fn from(error: reqwest::Error) -> Self {
-        Self::Unexpected(anyhow::Error::from(error))
+        // A connect timeout is reported as both a timeout and a connect error.
+        match (error.is_timeout(), error.is_connect()) {
+            (true, true) => Self::ConnectTimeout(error),
+            (true, false) => Self::ReadTimeout(error),
+            (false, true) if is_dns(&error) => Self::Dns(error),
+            (false, true) if is_tls(&error) => Self::Tls(error),
+            (false, true) => Self::Connect(error),
+            (false, false) => Self::Unexpected(anyhow::Error::from(error)),
+        }
     }
 }
 
+/// Walks the chain of errors that caused `error`, starting with `error` itself.
+fn causes<'a>(
+    error: &'a (dyn std::error::Error + 'static),
+) -> impl Iterator<Item = &'a (dyn std::error::Error + 'static)> {
+    std::iter::successors(Some(error), |error| {
+        // `io::Error::source` skips the error it wraps and goes straight to that error's source, which would skip
+        // over the `rustls::Error` that `tokio-rustls` wraps in an `io::Error`.
+        match error
+            .downcast_ref::<std::io::Error>()
+            .and_then(std::io::Error::get_ref)
+        {
+            Some(inner) => Some(inner as &(dyn std::error::Error + 'static)),
+            None => error.source(),
+        }
+    })
+}
+
+// Only lookups done by `client::dns::Resolver` can be recognized, which is what every `Client` uses.
+fn is_dns(error: &reqwest::Error) -> bool {
+    causes(error).any(|cause| cause.is::<ResolveError>())
+}
+
+fn is_tls(error: &reqwest::Error) -> bool {
+    causes(error).any(|cause| {
+        #[cfg(feature = "rustls-tls")]
+        let rustls = cause.is::<rustls::Error>();
+        #[cfg(not(feature = "rustls-tls"))]
+        let rustls = false;
+
+        #[cfg(feature = "native-tls")]
+        let native = cause.is::<native_tls::Error>();
+        #[cfg(not(feature = "native-tls"))]
+        let native = false;
+
+        rustls || native
+    })
+}
+
+/// A host that couldn't be resolved, returned by the resolver every [`Client`](super::Client) uses.
+#[derive(Debug, Error)]
+#[error("failed to resolve `{host}`")]
+pub(crate) struct ResolveError {
+    pub(crate) host: String,
+    #[source]
+    pub(crate) source: std::io::Error,
+}
+
 #[allow(missing_docs)]
 #[non_exhaustive]
 #[derive(Debug, Error)]
@@ -63,13 +137,15 @@ pub enum WebtoonError {
     NoGenre,
     #[error(transparent)]
     MalformedUrl(#[from] url::ParseError),
+    #[error("Webtoon was constructed as {expected:?}, but webtoons.com has it as {}", found.map_or_else(|| "neither type".to_string(), |found| format!("{found:?}")))]
+    ScopeMismatch { expected: Type, found: Option<Type> },
     #[error(transparent)]
     Unexpected(#[from] anyhow::Error),
 }
 
 impl From<reqwest::Error> for WebtoonError {
     fn from(error: reqwest::Error) -> Self {
-        Self::ClientError(ClientError::Unexpected(anyhow::Error::from(error)))
+        Self::ClientError(ClientError::from(error))
     }
 }
 
@@ -89,7 +165,7 @@ pub enum CreatorError {
 
 impl From<reqwest::Error> for CreatorError {
     fn from(error: reqwest::Error) -> Self {
-        Self::ClientError(ClientError::Unexpected(anyhow::Error::from(error)))
+        Self::ClientError(ClientError::from(error))
     }
 }
 
@@ -103,6 +179,8 @@ pub enum EpisodeError {
     NotViewable,
     #[error("Failed to find any panels for episode")]
     NoPanelsFound,
+    #[error("Episode uses the {0} viewer, which has no panels to get")]
+    UnsupportedViewer(Viewer),
     #[error("Failed to find a thumbnail for episode")]
     NoThumbnailFound,
     #[error(transparent)]
@@ -111,7 +189,7 @@ pub enum EpisodeError {
 
 impl From<reqwest::Error> for EpisodeError {
     fn from(error: reqwest::Error) -> Self {
-        Self::ClientError(ClientError::Unexpected(anyhow::Error::from(error)))
+        Self::ClientError(ClientError::from(error))
     }
 }
 
@@ -129,7 +207,7 @@ pub enum PostError {
 
 impl From<reqwest::Error> for PostError {
     fn from(error: reqwest::Error) -> Self {
-        Self::ClientError(ClientError::Unexpected(anyhow::Error::from(error)))
+        Self::ClientError(ClientError::from(error))
     }
 }
 
@@ -141,13 +219,15 @@ pub enum ReplyError {
     ClientError(#[from] ClientError),
     #[error("Post is deleted, cannot post reply on deleted post")]
     DeletedPost,
+    #[error("Parent must be a top-level comment on the same episode")]
+    InvalidParent,
     #[error(transparent)]
     Unexpected(#[from] anyhow::Error),
 }
 
 impl From<reqwest::Error> for ReplyError {
     fn from(error: reqwest::Error) -> Self {
-        Self::ClientError(ClientError::Unexpected(anyhow::Error::from(error)))
+        Self::ClientError(ClientError::from(error))
     }
 }
 
@@ -167,7 +247,7 @@ pub enum PosterError {
 
 impl From<reqwest::Error> for PosterError {
     fn from(error: reqwest::Error) -> Self {
-        Self::ClientError(ClientError::Unexpected(anyhow::Error::from(error)))
+        Self::ClientError(ClientError::from(error))
     }
 }
 
@@ -183,7 +263,7 @@ pub enum OriginalsError {
 
 impl From<reqwest::Error> for OriginalsError {
     fn from(error: reqwest::Error) -> Self {
-        Self::ClientError(ClientError::Unexpected(anyhow::Error::from(error)))
+        Self::ClientError(ClientError::from(error))
     }
 }
 
@@ -199,7 +279,7 @@ pub enum CanvasError {
 
 impl From<reqwest::Error> for CanvasError {
     fn from(error: reqwest::Error) -> Self {
-        Self::ClientError(ClientError::Unexpected(anyhow::Error::from(error)))
+        Self::ClientError(ClientError::from(error))
     }
 }
 
@@ -230,13 +310,123 @@ pub enum DownloadError {
     ClientError(#[from] ClientError),
     #[error(transparent)]
     IoError(#[from] std::io::Error),
+    #[error("{width}x{height} is too large to be saved in the chosen format")]
+    ImageTooLarge { width: u32, height: u32 },
+    #[error("Panel {panel} of episode {episode} was not fully downloaded")]
+    Corrupt { episode: u16, panel: u16 },
+    #[error("Episode uses the {0} viewer, which has no panels to download")]
+    UnsupportedViewer(Viewer),
     #[error(transparent)]
     Unexpected(#[from] anyhow::Error),
 }
 
+#[cfg(feature = "download")]
+impl DownloadError {
+    pub(crate) fn from_episode(error: EpisodeError) -> Self {
+        match error {
+            EpisodeError::ClientError(err) => Self::ClientError(err),
+            EpisodeError::UnsupportedViewer(viewer) => Self::UnsupportedViewer(viewer),
+            err => Self::Unexpected(err.into()),
+        }
+    }
+}
+
 #[cfg(feature = "download")]
 impl From<reqwest::Error> for DownloadError {
     fn from(error: reqwest::Error) -> Self {
         Self::ClientError(ClientError::from(error))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::platform::webtoons::client::dns::Resolver;
+    use std::time::Duration;
+    use tokio::{io::AsyncWriteExt, net::TcpListener};
+
+    fn client() -> reqwest::Client {
+        reqwest::Client::builder()
+            .dns_resolver(std::sync::Arc::new(Resolver))
+            .timeout(Duration::from_millis(500))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_classify_refused_connection_as_connect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+
+        let error = client()
+            .get(format!("http://{address}"))
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(ClientError::from(error), ClientError::Connect(_)));
+    }
+
+    #[tokio::test]
+    async fn should_classify_silent_server_as_read_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        // Accepts, but never responds.
+        let server = tokio::spawn(async move {
+            let (_stream, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+
+        let error = client()
+            .get(format!("http://{address}"))
+            .send()
+            .await
+            .unwrap_err();
+
+        server.abort();
+
+        assert!(matches!(
+            ClientError::from(error),
+            ClientError::ReadTimeout(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_classify_bad_handshake_as_tls() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        // Answers a TLS client hello with plain HTTP.
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+
+        let error = client()
+            .get(format!("https://{address}"))
+            .send()
+            .await
+            .unwrap_err();
+
+        server.abort();
+
+        assert!(matches!(ClientError::from(error), ClientError::Tls(_)));
+    }
+
+    #[tokio::test]
+    async fn should_classify_unknown_host_as_dns() {
+        let error = client()
+            .get("http://webtoon.invalid")
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(ClientError::from(error), ClientError::Dns(_)));
+    }
+}
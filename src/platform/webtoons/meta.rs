@@ -1,5 +1,6 @@
 //! Contains metadata implementations for webtoons.com.
 
+use crate::platform::genre::CommonGenre;
 use anyhow::bail;
 use serde::{Deserialize, Serialize};
 use std::{
@@ -69,6 +70,17 @@ impl Display for Language {
 }
 
 impl Language {
+    /// Every language `webtoons.com` has a site for, in declaration order.
+    pub const ALL: [Self; 7] = [
+        Self::En,
+        Self::Zh,
+        Self::Th,
+        Self::Id,
+        Self::Es,
+        Self::Fr,
+        Self::De,
+    ];
+
     /// Returns a string representation of the language.
     /// - En -> "en"
     /// - Zh -> "zh-hant"
@@ -111,6 +123,41 @@ impl Language {
             Self::Fr => "FRENCH",
         }
     }
+
+    /// Returns whether creator profile pages exist for this language.
+    ///
+    /// French, German, and Chinese do not have creator profiles on `webtoons.com`, and [`Client::creator`](super::Client::creator)
+    /// will return [`CreatorError::UnsupportedLanguage`](super::errors::CreatorError::UnsupportedLanguage) for them.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use webtoon::platform::webtoons::Language;
+    /// assert!(Language::En.supports_profiles());
+    /// assert!(!Language::Fr.supports_profiles());
+    /// ```
+    #[must_use]
+    pub const fn supports_profiles(self) -> bool {
+        !matches!(self, Self::Zh | Self::De | Self::Fr)
+    }
+
+    /// Returns whether the creator dashboard can be used for this language.
+    ///
+    /// Only English dashboards are currently supported. For every other language, methods like
+    /// [`Webtoon::views`](super::Webtoon::views) and [`Webtoon::subscribers`](super::Webtoon::subscribers)
+    /// fall back to the rounded public data, even with a creator session.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use webtoon::platform::webtoons::Language;
+    /// assert!(Language::En.supports_dashboards());
+    /// assert!(!Language::Th.supports_dashboards());
+    /// ```
+    #[must_use]
+    pub const fn supports_dashboards(self) -> bool {
+        matches!(self, Self::En)
+    }
 }
 
 /// Represents the type a webtoon can be on webtoons.com.
@@ -274,6 +321,90 @@ impl Genre {
             Self::WebNovel => "web-novel",
         }
     }
+
+    /// Parses a genre code from the community API, like `SLICE_OF_LIFE` or `SUPER_HERO`.
+    ///
+    /// Returns `None` for codes that don't map to a known genre.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use webtoon::platform::webtoons::meta::Genre;
+    /// assert_eq!(Some(Genre::SliceOfLife), Genre::from_api_slug("SLICE_OF_LIFE"));
+    /// assert_eq!(Some(Genre::Superhero), Genre::from_api_slug("SUPER_HERO"));
+    /// assert_eq!(None, Genre::from_api_slug("NOT_A_GENRE"));
+    /// ```
+    #[must_use]
+    pub fn from_api_slug(slug: &str) -> Option<Self> {
+        let slug = slug.trim().to_ascii_lowercase().replace('_', "-");
+
+        let genre = match slug.as_str() {
+            "sf" | "scifi" | "sci-fi" => Self::SciFi,
+            "all-ages" => Self::AllAges,
+            "short-story" => Self::ShortStory,
+            "post-apocalyptic" | "post-apocalypse" => Self::PostApocalyptic,
+            "informative" | "tiptoon" => Self::Informative,
+            "lgbtq" | "bl" | "gl" => Self::LGBTQ,
+            "super-hero" => Self::Superhero,
+            "mature-romance" => Self::MatureRomance,
+            "webnovel" => Self::WebNovel,
+            slug => return Self::from_str(slug).ok(),
+        };
+
+        Some(genre)
+    }
+
+    /// Maps the genre into the [`CommonGenre`] shared across platforms.
+    ///
+    /// Returns `None` for genres that have no clean counterpart, such as `RomanticFantasy`, which straddles both
+    /// `Romance` and `Fantasy`, or genres specific to `webtoons.com`, like `Informative`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use webtoon::platform::{genre::CommonGenre, webtoons::meta::Genre};
+    /// assert_eq!(Some(CommonGenre::Fantasy), Genre::Fantasy.to_common());
+    /// assert_eq!(None, Genre::RomanticFantasy.to_common());
+    /// ```
+    #[must_use]
+    pub const fn to_common(self) -> Option<CommonGenre> {
+        match self {
+            Self::Comedy => Some(CommonGenre::Comedy),
+            Self::Fantasy => Some(CommonGenre::Fantasy),
+            Self::Romance | Self::MatureRomance => Some(CommonGenre::Romance),
+            Self::SliceOfLife => Some(CommonGenre::SliceOfLife),
+            Self::Action => Some(CommonGenre::Action),
+            Self::Drama => Some(CommonGenre::Drama),
+            Self::Thriller => Some(CommonGenre::Thriller),
+            Self::Historical => Some(CommonGenre::Historical),
+            Self::Sports => Some(CommonGenre::Sports),
+            Self::Heartwarming => Some(CommonGenre::Heartwarming),
+            Self::SciFi
+            | Self::ShortStory
+            | Self::Superhero
+            | Self::Horror
+            | Self::PostApocalyptic
+            | Self::Zombies
+            | Self::School
+            | Self::Supernatural
+            | Self::Animals
+            | Self::Mystery
+            | Self::Informative
+            | Self::Inspirational
+            | Self::AllAges
+            | Self::LGBTQ
+            | Self::RomanticFantasy
+            | Self::MartialArts
+            | Self::WesternPalace
+            | Self::EasternPalace
+            | Self::TimeSlip
+            | Self::Local
+            | Self::CityOffice
+            | Self::Adaptation
+            | Self::Shonen
+            | Self::WebNovel => None,
+        }
+    }
 }
 
 /// An error that can happen when parsing a string into a [`Genre`].
@@ -397,4 +528,16 @@ mod test {
             Ok(())
         }
     }
+
+    #[test]
+    fn should_parse_genres_from_api_slugs() {
+        pretty_assertions::assert_eq!(Some(Genre::SciFi), Genre::from_api_slug("SF"));
+        pretty_assertions::assert_eq!(Some(Genre::AllAges), Genre::from_api_slug("ALL_AGES"));
+        pretty_assertions::assert_eq!(
+            Some(Genre::PostApocalyptic),
+            Genre::from_api_slug("POST_APOCALYPTIC")
+        );
+        pretty_assertions::assert_eq!(Some(Genre::LGBTQ), Genre::from_api_slug("BL_GL"));
+        pretty_assertions::assert_eq!(None, Genre::from_api_slug(""));
+    }
 }
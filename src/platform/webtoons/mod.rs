@@ -9,6 +9,8 @@ pub mod webtoon;
 pub mod canvas;
 pub mod originals;
 
+mod cdn;
+
 pub use meta::{Language, Type};
 
 pub use client::{Client, ClientBuilder};
@@ -1,14 +1,15 @@
 //! Represents an abstraction for the `https://www.webtoons.com/*/originals` endpoint.
 
-use std::str::FromStr;
+use std::{str::FromStr, sync::Arc};
 
 // mod genres;
 use anyhow::Context;
-use scraper::{Html, Selector};
+use scraper::{ElementRef, Html, Selector};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use tokio::sync::Mutex;
 
-use super::{errors::OriginalsError, Client, Language, Webtoon};
+use super::{errors::OriginalsError, meta::Scope, Client, Language, Type, Webtoon};
 
 pub(super) async fn scrape(
     client: &Client,
@@ -20,7 +21,7 @@ pub(super) async fn scrape(
 
     let mut webtoons = Vec::with_capacity(1000);
 
-    let document = client.get_originals_page(language).await?.text().await?;
+    let document = client.get_originals_page(language).await?;
 
     let html = Html::parse_document(&document);
 
@@ -39,6 +40,149 @@ pub(super) async fn scrape(
     Ok(webtoons)
 }
 
+pub(super) async fn listing(
+    client: &Client,
+    language: Language,
+) -> Result<Vec<WebtoonListing>, OriginalsError> {
+    let selector = Selector::parse("ul.daily_card>li>a") //
+        .expect("`ul.daily_card>li>a` should be a valid selector");
+
+    let document = client.get_originals_page(language).await?;
+
+    let html = Html::parse_document(&document);
+
+    let mut listings = Vec::with_capacity(1000);
+
+    for card in html.select(&selector) {
+        listings.push(WebtoonListing::from_card(card, client)?);
+    }
+
+    Ok(listings)
+}
+
+/// A lightweight entry from the originals list, holding only what the card markup shows.
+///
+/// Getting a list of these is a single request, and nothing else is fetched until a method on the
+/// [`Webtoon`] returned by [`into_webtoon`](WebtoonListing::into_webtoon) needs it.
+#[derive(Debug, Clone)]
+pub struct WebtoonListing {
+    id: u32,
+    language: Language,
+    scope: Scope,
+    slug: Arc<str>,
+    title: String,
+    thumbnail: String,
+}
+
+impl WebtoonListing {
+    /// Returns the id of the webtoon.
+    #[must_use]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the [`Type`] of the webtoon: `Original` or `Canvas`.
+    #[must_use]
+    pub fn r#type(&self) -> Type {
+        match self.scope {
+            Scope::Original(_) => Type::Original,
+            Scope::Canvas => Type::Canvas,
+        }
+    }
+
+    /// Returns the url slug of the webtoon: Tower of God -> `tower-of-god`.
+    #[must_use]
+    pub fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    /// Returns the title of the webtoon as shown on the card.
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the thumbnail url of the webtoon as shown on the card.
+    #[must_use]
+    pub fn thumbnail(&self) -> &str {
+        &self.thumbnail
+    }
+
+    /// Turns the listing into a [`Webtoon`] so that interaction can be done on it.
+    ///
+    /// No request is made here; the returned `Webtoon` lazily fetches its page the first time it is needed.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{errors::Error, Client, Language};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = Client::new();
+    ///
+    /// let listings = client.originals_listing(Language::En).await?;
+    ///
+    /// if let Some(listing) = listings.iter().find(|listing| listing.title() == "Tower of God") {
+    ///     let webtoon = listing.into_webtoon(&client);
+    ///     println!("views: {}", webtoon.views().await?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn into_webtoon(&self, client: &Client) -> Webtoon {
+        Webtoon {
+            client: client.clone(),
+            id: self.id,
+            language: self.language,
+            scope: self.scope,
+            slug: Arc::clone(&self.slug),
+            page: Arc::new(Mutex::new(None)),
+            genres: None,
+        }
+    }
+
+    fn from_card(card: ElementRef<'_>, client: &Client) -> Result<Self, OriginalsError> {
+        let title_selector = Selector::parse("p.subj") //
+            .expect("`p.subj` should be a valid selector");
+
+        let img_selector = Selector::parse("img") //
+            .expect("`img` should be a valid selector");
+
+        let href = card
+            .attr("href")
+            .context("`href` is missing, `a` tag should always have one")?;
+
+        // Only parses the url, no request is made.
+        let webtoon = Webtoon::from_url_with_client(href, client)?;
+
+        let img = card.select(&img_selector).next();
+
+        let title = match card.select(&title_selector).next() {
+            Some(subj) => subj.text().collect::<String>().trim().to_string(),
+            None => img
+                .and_then(|img| img.attr("alt"))
+                .context("originals card should have either a `p.subj` or an `img` with `alt` for the title")?
+                .trim()
+                .to_string(),
+        };
+
+        let thumbnail = img
+            .and_then(|img| img.attr("src"))
+            .context("originals card should have an `img` with a `src`")?
+            .to_string();
+
+        Ok(Self {
+            id: webtoon.id,
+            language: webtoon.language,
+            scope: webtoon.scope,
+            slug: webtoon.slug,
+            title,
+            thumbnail,
+        })
+    }
+}
+
 /// Represents a kind of release schedule for Originals.  
 ///
 /// For the days of the week, a webtoon can have multiple.
@@ -173,3 +317,30 @@ impl FromStr for Release {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_parse_listing_from_card() {
+        let html = Html::parse_fragment(
+            r#"<ul class="daily_card"><li><a href="https://www.webtoons.com/en/fantasy/tower-of-god/list?title_no=95" class="daily_card_item"><img src="https://webtoon-phinf.pstatic.net/tower.jpg" alt="Tower of God"><div class="info"><p class="genre g_fantasy">Fantasy</p><p class="subj"> Tower of God </p></div></a></li></ul>"#,
+        );
+
+        let selector = Selector::parse("ul.daily_card>li>a").unwrap();
+        let card = html.select(&selector).next().unwrap();
+
+        let listing = WebtoonListing::from_card(card, &Client::new()).unwrap();
+
+        assert_eq!(95, listing.id());
+        assert_eq!(Type::Original, listing.r#type());
+        assert_eq!("tower-of-god", listing.slug());
+        assert_eq!("Tower of God", listing.title());
+        assert_eq!(
+            "https://webtoon-phinf.pstatic.net/tower.jpg",
+            listing.thumbnail()
+        );
+    }
+}
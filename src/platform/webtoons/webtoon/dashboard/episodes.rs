@@ -26,6 +26,7 @@ pub async fn scrape(webtoon: &Webtoon) -> Result<Vec<Episode>, EpisodeError> {
         episodes.insert(Episode {
             webtoon: webtoon.clone(),
             number: episode.metadata.number,
+            display_number: None,
             season: Arc::new(Mutex::new(super::super::episode::season(
                 &episode.metadata.title,
             ))),
@@ -35,6 +36,7 @@ pub async fn scrape(webtoon: &Webtoon) -> Result<Vec<Episode>, EpisodeError> {
                     .expect("webtoons should be using proper timestamps")
             }),
             page: Arc::new(Mutex::new(None)),
+            comments_and_replies: Arc::new(Mutex::new(None)),
             views: Some(episode.metadata.views),
             ad_status: Some(episode.dashboard_status.ad_status()),
             published_status: Some(episode.dashboard_status.into()),
@@ -42,6 +44,8 @@ pub async fn scrape(webtoon: &Webtoon) -> Result<Vec<Episode>, EpisodeError> {
     }
 
     for page in 2..=pages {
+        webtoon.client.check_cancelled()?;
+
         let response = webtoon
             .client
             .get_episodes_dashboard(webtoon, page)
@@ -55,6 +59,7 @@ pub async fn scrape(webtoon: &Webtoon) -> Result<Vec<Episode>, EpisodeError> {
             episodes.insert(Episode {
                 webtoon: webtoon.clone(),
                 number: episode.metadata.number,
+                display_number: None,
                 season: Arc::new(Mutex::new(super::super::episode::season(
                     &episode.metadata.title,
                 ))),
@@ -64,6 +69,7 @@ pub async fn scrape(webtoon: &Webtoon) -> Result<Vec<Episode>, EpisodeError> {
                         .expect("webtoons should be using proper timestamps")
                 }),
                 page: Arc::new(Mutex::new(None)),
+                comments_and_replies: Arc::new(Mutex::new(None)),
                 views: Some(episode.metadata.views),
                 ad_status: Some(episode.dashboard_status.ad_status()),
                 published_status: Some(episode.dashboard_status.into()),
@@ -80,6 +86,52 @@ pub async fn scrape(webtoon: &Webtoon) -> Result<Vec<Episode>, EpisodeError> {
     Ok(episodes)
 }
 
+/// Gets the views for a single episode from the dashboard, only requesting the page that the episode would be listed on.
+pub async fn views(webtoon: &Webtoon, number: u16) -> Result<Option<u32>, EpisodeError> {
+    let response = webtoon
+        .client
+        .get_episodes_dashboard(webtoon, 1)
+        .await?
+        .text()
+        .await?;
+
+    let dashboard_episodes = DashboardEpisode::parse(&response)?;
+
+    let Some(latest) = dashboard_episodes
+        .first()
+        .map(|episode| episode.metadata.number)
+    else {
+        return Ok(None);
+    };
+
+    if number > latest {
+        return Ok(None);
+    }
+
+    // Dashboard is ordered newest to oldest, 10 per page.
+    let page = (latest - number) / 10 + 1;
+
+    let dashboard_episodes = if page == 1 {
+        dashboard_episodes
+    } else {
+        let response = webtoon
+            .client
+            .get_episodes_dashboard(webtoon, page)
+            .await?
+            .text()
+            .await?;
+
+        DashboardEpisode::parse(&response)?
+    };
+
+    let views = dashboard_episodes
+        .into_iter()
+        .find(|episode| episode.metadata.number == number)
+        .map(|episode| episode.metadata.views);
+
+    Ok(views)
+}
+
 fn calculate_max_pages(html: &str) -> Result<u16, EpisodeError> {
     let episodes = DashboardEpisode::parse(html)?;
 
@@ -5,30 +5,39 @@ pub mod posts;
 
 pub use page::panels::Panel;
 #[cfg(feature = "download")]
-pub use page::panels::Panels;
+pub use page::panels::{Format, Panels};
 
 use anyhow::Context;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
 use core::fmt;
 use posts::Post;
 use regex::Regex;
 use scraper::Html;
 use serde_json::json;
-use std::collections::HashSet;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use std::collections::{BTreeMap, HashSet};
 use std::future::Future;
+use std::io::Write;
+use std::ops::ControlFlow;
 use std::sync::Arc;
 use std::{hash::Hash, str::FromStr};
+use thiserror::Error;
 use tokio::sync::Mutex;
 
 use self::page::Page;
-use self::posts::Posts;
-use crate::platform::webtoons::client::likes::Likes;
+use self::posts::{PostSort, Posts};
+use crate::platform::webtoons::client::likes::{self, Likes};
 use crate::platform::webtoons::client::posts::id::Id;
-use crate::platform::webtoons::client::posts::PostsResult;
+use crate::platform::webtoons::client::posts::{PostResult, PostsResult};
+use crate::platform::webtoons::client::trace::Traced;
+#[cfg(feature = "download")]
+use crate::platform::webtoons::{errors::DownloadError, Client};
 use crate::platform::webtoons::{
-    errors::{ClientError, EpisodeError, PostError},
+    errors::{ClientError, EpisodeError, PostError, ReplyError},
     meta::Scope,
+    Language, Type,
 };
+use crate::stdx::lru::Lru;
 
 use super::{dashboard::episodes::DashboardStatus, Webtoon};
 
@@ -82,10 +91,13 @@ use super::{dashboard::episodes::DashboardStatus, Webtoon};
 pub struct Episode {
     pub(crate) webtoon: Webtoon,
     pub(crate) number: u16,
+    pub(crate) display_number: Option<u16>,
     pub(crate) season: Arc<Mutex<Option<u8>>>,
     pub(crate) title: Arc<Mutex<Option<String>>>,
     pub(crate) published: Option<DateTime<Utc>>,
     pub(crate) page: Arc<Mutex<Option<Page>>>,
+    /// `(comments, replies)` from the first posts response, shared with `comments_and_replies`.
+    pub(crate) comments_and_replies: Arc<Mutex<Option<(u32, u32)>>>,
     pub(crate) views: Option<u32>,
     pub(crate) ad_status: Option<AdStatus>,
     pub(crate) published_status: Option<PublishedStatus>,
@@ -97,10 +109,12 @@ impl fmt::Debug for Episode {
         f.debug_struct("Episode")
             // omitting `webtoon`
             .field("number", &self.number)
+            .field("display_number", &self.display_number)
             .field("season", &self.season)
             .field("title", &self.title)
             .field("published", &self.published)
             .field("page", &self.page)
+            .field("comments_and_replies", &self.comments_and_replies)
             .field("views", &self.views)
             .field("ad_status", &self.ad_status)
             .field("published_status", &self.published_status)
@@ -109,6 +123,9 @@ impl fmt::Debug for Episode {
 }
 
 impl Episode {
+    /// The scroll speed, in pixels per second, that [`Episode::estimated_read_seconds`] assumes.
+    pub const DEFAULT_SCROLL_SPEED: u32 = 250;
+
     /// Returns the episode number.
     ///
     /// This matches up with the `episode_no=` URL query. This does not necessarily match up with the `#NUMBER` on the episode list.
@@ -117,25 +134,22 @@ impl Episode {
         self.number
     }
 
+    /// Returns the `#N` the website shows for this episode in the episode list.
+    ///
+    /// Unlike [`number`](Episode::number), this skips over deleted episodes, so it matches what readers see on the
+    /// site. It is only known for episodes from [`Webtoon::episodes`] built from the public episode list, and is
+    /// `None` otherwise, including when a creator session makes `episodes` use the dashboard instead.
+    #[must_use]
+    pub const fn display_number(&self) -> Option<u16> {
+        self.display_number
+    }
+
     /// Returns the title of the episode.
     pub async fn title(&self) -> Result<String, EpisodeError> {
         let mut title = self.title.lock().await;
 
         if title.is_none() {
-            let mut page = self.page.lock().await;
-
-            if page.is_none() {
-                *page = Some(self.scrape().await?);
-            }
-
-            *title = Some(
-                page.as_ref()
-                    .context(
-                        "page should have been scraped with `self.scrape` and so should be `Some`",
-                    )?
-                    .title
-                    .clone(),
-            );
+            *title = Some(self.with_page(|page| page.title.clone()).await?);
         }
 
         Ok(title
@@ -189,40 +203,118 @@ impl Episode {
         Ok(*season)
     }
 
-    /// Returns the creator note for episode.
-    pub async fn note(&self) -> Result<Option<String>, EpisodeError> {
-        let mut page = self.page.lock().await;
+    /// Returns the URL of the episode's viewer on webtoons.com.
+    ///
+    /// The episode part of the path is not checked by webtoons.com, so `episode-{number}` is used in its place.
+    #[must_use]
+    pub fn url(&self) -> String {
+        let webtoon = &self.webtoon;
+
+        format!(
+            "https://www.webtoons.com/{}/{}/{}/episode-{}/viewer?title_no={}&episode_no={}",
+            webtoon.language,
+            webtoon.scope.as_slug(),
+            webtoon.slug,
+            self.number,
+            webtoon.id,
+            self.number
+        )
+    }
 
-        if page.is_none() {
-            *page = Some(self.scrape().await?);
-        }
+    /// Returns an id for the episode that is stable and unique across platforms, of the form
+    /// `webtoons:{language}:{type}:{webtoon id}:{episode number}`, e.g. `webtoons:en:canvas:95:42`, where the type is
+    /// `original` or `canvas`.
+    ///
+    /// Webtoon ids are only unique within a language and type, so both are part of the id. This makes it usable as a
+    /// key when merging data from different platforms, unlike comparing `Episode`s, which only looks at the number.
+    #[must_use]
+    pub fn global_id(&self) -> String {
+        let r#type = match self.webtoon.r#type() {
+            Type::Original => "original",
+            Type::Canvas => "canvas",
+        };
 
-        let note = page
-            .as_ref()
-            .map(|page| page.note.clone())
-            .context("episode `page` should have been updated with the call to `self.scrape`")?;
+        format!(
+            "webtoons:{}:{type}:{}:{}",
+            self.webtoon.language, self.webtoon.id, self.number
+        )
+    }
 
-        drop(page);
+    /// Returns the creator note for episode.
+    pub async fn note(&self) -> Result<Option<String>, EpisodeError> {
+        self.with_page(|page| page.note.clone()).await
+    }
 
-        Ok(note)
+    /// Returns the notice webtoons.com shows on the episode, like a hiatus announcement or schedule change, if any.
+    ///
+    /// This is an official announcement, and is kept apart from the creator's own [`Episode::note`].
+    pub async fn notice(&self) -> Result<Option<String>, EpisodeError> {
+        self.with_page(|page| page.notice.clone()).await
     }
 
     /// Returns the sum of the vertical length in pixels.
     pub async fn length(&self) -> Result<u32, EpisodeError> {
-        let mut page = self.page.lock().await;
+        self.with_page(|page| page.length).await
+    }
 
-        if page.is_none() {
-            *page = Some(self.scrape().await?);
-        }
+    /// Returns the `(width, height)` of the episode with its panels stacked vertically, without downloading any of them.
+    ///
+    /// The width is that of the widest panel, and the height is the sum of all panel heights, both read from the
+    /// attributes on the viewer page. These are only what the page displays the panels at, so they can be off from
+    /// the downloaded images, but are enough to tell whether an episode is too big to be worth downloading.
+    ///
+    /// Returns `None` for episodes without panels, like those that use the audio or motion viewer.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # if let Some(episode) = webtoon.episode(1).await? {
+    /// if let Some((width, height)) = episode.total_dimensions().await? {
+    ///     println!("{width}x{height}");
+    /// }
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - Returns an [`EpisodeError`] if there is a failure in fetching or processing the episode data.
+    pub async fn total_dimensions(&self) -> Result<Option<(u32, u32)>, EpisodeError> {
+        let dimensions = self
+            .panels()
+            .await
+            .map(|panels| panels.iter().map(Panel::dimensions).collect());
 
-        let length = page
-            .as_ref()
-            .context("episode `page` should have been updated with the call to `self.scrape`")?
-            .length;
+        stacked_dimensions(dimensions)
+    }
 
-        drop(page);
+    /// Returns an estimate of how many seconds it takes to scroll through the episode, at [`Episode::DEFAULT_SCROLL_SPEED`].
+    ///
+    /// This is only a rough estimate from [`Episode::length`], and doesn't account for how much text is in the panels.
+    /// Use [`Episode::estimated_read_seconds_at`] for a different scroll speed.
+    ///
+    /// Returns `None` for episodes that use a special viewer without panels, like the audio or motion viewer, as they
+    /// have no length to measure.
+    pub async fn estimated_read_seconds(&self) -> Result<Option<u32>, EpisodeError> {
+        self.estimated_read_seconds_at(Self::DEFAULT_SCROLL_SPEED)
+            .await
+    }
 
-        Ok(length)
+    /// Same as [`Episode::estimated_read_seconds`], but scrolling at `pixels_per_second`.
+    ///
+    /// A `pixels_per_second` of `0` is treated as `1`.
+    pub async fn estimated_read_seconds_at(
+        &self,
+        pixels_per_second: u32,
+    ) -> Result<Option<u32>, EpisodeError> {
+        read_seconds(self.length().await, pixels_per_second)
     }
 
     /// Returns the published timestamp of the episode.
@@ -271,6 +363,52 @@ impl Episode {
         self.published.map(|datetime| datetime.timestamp_millis())
     }
 
+    /// Returns the published date and time of the episode in the given timezone.
+    ///
+    /// Any [`TimeZone`] can be used, like [`chrono::Local`], [`chrono::FixedOffset`], or a `chrono_tz::Tz`.
+    ///
+    /// This has the same availability as [`Episode::published`], returning `None` when that does.
+    ///
+    /// ### Caveat
+    ///
+    /// The public episode list only has the date an episode was published, not the time. When the date comes from there,
+    /// it's treated as being published at 2:00 AM UTC. Converting that to a timezone behind UTC-2, like those in the
+    /// Americas, will land on the day before. If only the date matters, use [`Episode::published_date`] instead.
+    ///
+    /// Dates from the creator dashboard, when using a creator session, have the exact time.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// let tokyo = chrono::FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    /// for episode in webtoon.episodes().await? {
+    ///     if let Some(published) = episode.published_in(&tokyo) {
+    ///         println!("Episode {} was published {published}", episode.number());
+    ///     }
+    /// }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn published_in<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Tz>> {
+        self.published.map(|datetime| datetime.with_timezone(tz))
+    }
+
+    /// Returns the date the episode was published, as shown on `webtoons.com`, in UTC.
+    ///
+    /// Unlike [`Episode::published_in`], this isn't affected by the 2:00 AM UTC placeholder time, so it's always the same
+    /// calendar date as the one on the episode list.
+    #[must_use]
+    pub fn published_date(&self) -> Option<NaiveDate> {
+        self.published.map(|datetime| datetime.date_naive())
+    }
+
     /// Returns the view count for the episode as `Some(u32)` if available, or `None` if the view count is not accessible.
     ///
     /// ### Behavior
@@ -306,14 +444,152 @@ impl Episode {
     /// ### Notes
     ///
     /// - View counts for episodes behind fast-pass, ad walls, or unpublished drafts are only available when the session belongs to the creator.
-    /// - If the episode is accessed using `webtoon.episode(N)`, the view count will always return [`None`].
+    /// - If the episode is accessed using `webtoon.episode(N)`, the view count will always return [`None`]. Use [`Episode::fetch_views`] to get it on demand.
     #[must_use]
     pub fn views(&self) -> Option<u32> {
         self.views
     }
 
+    /// Fetches the view count for just this episode from the creator dashboard.
+    ///
+    /// Unlike [`Episode::views`], this works for episodes gotten with `webtoon.episode(N)`, without needing to pull every
+    /// episode with `webtoon.episodes()`. If the view count is already known, no request is made.
+    ///
+    /// ### Returns
+    ///
+    /// - `Ok(Some(u32))`: The session belongs to the creator of the webtoon and the episode was found on the dashboard.
+    /// - `Ok(None)`: No session was provided, the session is not the creator's, the language has no dashboard support
+    ///   (see [`Language::supports_dashboards`](crate::platform::webtoons::Language::supports_dashboards)), or the episode isn't on the dashboard.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = Client::with_session("my-session");
+    ///
+    /// if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    ///     if let Some(episode) = webtoon.episode(1).await? {
+    ///         if let Some(views) = episode.fetch_views().await? {
+    ///             println!("Episode 1 has {views} views.");
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `EpisodeError::ClientError`: If the session is invalid or there was an issue with the request.
+    /// - `EpisodeError::Unexpected`: If the dashboard could not be parsed.
+    pub async fn fetch_views(&self) -> Result<Option<u32>, EpisodeError> {
+        if self.views.is_some() {
+            return Ok(self.views);
+        }
+
+        match self
+            .webtoon
+            .client
+            .get_user_info_for_webtoon(&self.webtoon)
+            .await
+        {
+            Ok(user)
+                if user.is_webtoon_creator() && self.webtoon.language.supports_dashboards() =>
+            {
+                super::dashboard::episodes::views(&self.webtoon, self.number).await
+            }
+            Ok(_) | Err(ClientError::NoSessionProvided) => Ok(None),
+            Err(err) => Err(EpisodeError::ClientError(err)),
+        }
+    }
+
+    /// Returns the view count shown on the episode page, scraping it if it wasn't already.
+    ///
+    /// Only some language sites show it, so this is `None` on the rest.
+    pub(super) async fn public_views(&self) -> Result<Option<u32>, EpisodeError> {
+        self.with_page(|page| page.views).await
+    }
+
+    /// Returns the sum of the super likes sent along with the episode's top-level comments.
+    ///
+    /// This is not a total from webtoons.com, but the sum of [`Poster::super_like`](posts::Poster::super_like) over the
+    /// comments [`Episode::posts`] fetches, at the same cost. Super likes on comments that were deleted without replies
+    /// are no longer fetched, so they aren't counted.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # if let Some(episode) = webtoon.episode(1).await? {
+    /// let super_likes = episode.super_likes_from_posts().await?;
+    /// println!("Comments on episode 1 came with {super_likes} super likes.");
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `EpisodeError::ClientError`: If there was an issue with the request.
+    /// - `EpisodeError::Unexpected`: If the posts could not be parsed.
+    pub async fn super_likes_from_posts(&self) -> Result<u32, EpisodeError> {
+        let posts = self.posts().await.map_err(|err| match err {
+            PostError::ClientError(client_error) => EpisodeError::ClientError(client_error),
+            error => EpisodeError::Unexpected(error.into()),
+        })?;
+
+        let super_likes = posts
+            .into_iter()
+            .filter_map(|post| post.poster().super_like())
+            .sum();
+
+        Ok(super_likes)
+    }
+
     /// Returns the like count for the episode.
     pub async fn likes(&self) -> Result<u32, EpisodeError> {
+        Ok(self.reaction().await?.count())
+    }
+
+    /// Returns the count of each reaction on the episode, keyed by the reaction id webtoons.com uses, like `like`.
+    ///
+    /// [`Episode::likes`] only reads the first reaction. This returns all of them as given, so reaction types added
+    /// later show up here without this crate needing to know about them.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # if let Some(episode) = webtoon.episode(1).await? {
+    /// for (reaction, count) in episode.reactions().await? {
+    ///     println!("{reaction}: {count}");
+    /// }
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - Returns an [`EpisodeError`] if the request failed or the response couldn't be parsed.
+    pub async fn reactions(&self) -> Result<BTreeMap<String, u32>, EpisodeError> {
+        Ok(self.reaction().await?.counts())
+    }
+
+    /// Gets the reactions on this episode from the likes api, which [`Episode::likes`] and [`Episode::reactions`] read.
+    async fn reaction(&self) -> Result<likes::Reaction, EpisodeError> {
         let response = self
             .webtoon
             .client
@@ -324,23 +600,25 @@ impl Episode {
 
         let api = serde_json::from_str::<Likes>(&response).context(response)?;
 
-        let api = api.result.contents.first().context(
-        "`contents` field  in likes api didn't have a 0th element and it should always have one",
-    )?;
-
-        let likes = api
-            .reactions
-            .first()
-            .map(|likes| likes.count)
-            .unwrap_or_default();
+        let reaction = api.result.contents.into_iter().next().context(
+            "`contents` field in likes api didn't have a 0th element and it should always have one",
+        )?;
 
-        Ok(likes)
+        Ok(reaction)
     }
 
     /// Returns the comment and reply count for the episode.
     ///
     /// Tuple is returned as `(comments, replies)`.
+    ///
+    /// The counts are kept from the first request, including the one made by [`Episode::posts`] and the other post
+    /// getters, so asking again on the same `Episode` makes no further requests. They are fetched again after posting,
+    /// replying, or deleting a post through this `Episode` or its posts, and after [`Episode::evict_cache`].
     pub async fn comments_and_replies(&self) -> Result<(u32, u32), PostError> {
+        if let Some(counts) = *self.comments_and_replies.lock().await {
+            return Ok(counts);
+        }
+
         let response = self
             .webtoon
             .client
@@ -351,10 +629,141 @@ impl Episode {
 
         let api = serde_json::from_str::<PostsResult>(&response).context(response)?;
 
+        Ok(self.cache_comments_and_replies(&api).await)
+    }
+
+    /// Returns when the oldest and newest comments on the episode were posted, as `(oldest, newest)` in milliseconds
+    /// since the Unix epoch.
+    ///
+    /// Only the first post of a newest-first and an oldest-first request is fetched, so this costs two requests no
+    /// matter how many comments the episode has.
+    ///
+    /// ### Returns
+    ///
+    /// - `Ok(Some((oldest, newest)))`: The bounds of the comment activity. Both are the same with a single comment.
+    /// - `Ok(None)`: If the episode has no comments.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # if let Some(episode) = webtoon.episode(1).await? {
+    /// if let Some((oldest, newest)) = episode.comment_time_range().await? {
+    ///     println!("Comments span {} days", (newest - oldest) / 86_400_000);
+    /// }
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `PostError::ClientError`: If there was an issue with the request.
+    /// - `PostError::Unexpected`: If the response could not be parsed.
+    pub async fn comment_time_range(&self) -> Result<Option<(i64, i64)>, PostError> {
+        let Some(newest) = self.first_posted(PostSort::Newest).await? else {
+            return Ok(None);
+        };
+
+        let oldest = self.first_posted(PostSort::Oldest).await?.unwrap_or(newest);
+
+        Ok(Some((oldest, newest)))
+    }
+
+    /// When the first post in `sort` order was posted, or `None` if there are no posts.
+    async fn first_posted(&self, sort: PostSort) -> Result<Option<i64>, PostError> {
+        let response = self
+            .webtoon
+            .client
+            .get_sorted_posts_for_episode(self, None, 1, sort)
+            .await?
+            .text()
+            .await?;
+
+        let api = serde_json::from_str::<PostsResult>(&response).context(response)?;
+
+        self.cache_comments_and_replies(&api).await;
+
+        let Some(post) = api.result.posts.into_iter().next() else {
+            return Ok(None);
+        };
+
+        Ok(Some(Post::try_from((self, post))?.posted()))
+    }
+
+    async fn cache_comments_and_replies(&self, api: &PostsResult) -> (u32, u32) {
         let comments = api.result.active_root_post_count;
         let replies = api.result.active_post_count - comments;
 
-        Ok((comments, replies))
+        *self.comments_and_replies.lock().await = Some((comments, replies));
+
+        (comments, replies)
+    }
+
+    /// Drops the cached comment and reply counts, for after a post on this episode was made or deleted.
+    pub(super) async fn forget_comments_and_replies(&self) {
+        *self.comments_and_replies.lock().await = None;
+    }
+
+    /// Retrieves the current state of a single post on this episode, by its [`Id`].
+    ///
+    /// Only the one post is requested, so this is much cheaper than [`Episode::posts`] when following the upvotes or
+    /// deletion of known posts.
+    ///
+    /// ### Returns
+    ///
+    /// - `Ok(Some(Post))`: The post as it currently is.
+    /// - `Ok(None)`: If the post no longer exists, or the id is for a post on a different episode.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # if let Some(episode) = webtoon.episode(1).await? {
+    /// let id = "GW-epicom:0-c_843910_1-1d".parse()?;
+    ///
+    /// if let Some(post) = episode.post_by_id(id).await? {
+    ///     println!("{} upvotes, deleted: {}", post.upvotes(), post.is_deleted());
+    /// }
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `PostError::ClientError`: If there was an issue with the request.
+    /// - `PostError::Unexpected`: If the response could not be parsed.
+    pub async fn post_by_id(&self, id: Id) -> Result<Option<Post>, PostError> {
+        if !id.is_on_page(
+            self.webtoon.scope.as_single_letter(),
+            self.webtoon.id,
+            self.number,
+        ) {
+            return Ok(None);
+        }
+
+        let response = self.webtoon.client.get_post(id).await?;
+
+        if response.status() == 404 {
+            return Ok(None);
+        }
+
+        let response = response.text().await?;
+
+        let api = serde_json::from_str::<PostResult>(&response).context(response)?;
+
+        Ok(Some(Post::try_from((self, api.result))?))
     }
 
     /// Retrieves the direct (top-level) comments for the episode, sorted from newest to oldest.
@@ -402,45 +811,183 @@ impl Episode {
         )]
         let mut posts = HashSet::new();
 
-        let response = self
-            .webtoon
-            .client
-            .get_posts_for_episode(self, None, 100)
-            .await?
-            .text()
-            .await?;
+        let (page, mut next) = self.posts_page(None, 100).await?;
+        posts.extend(page);
 
-        let api = serde_json::from_str::<PostsResult>(&response).context(response)?;
+        // Get rest if any
+        while let Some(cursor) = next {
+            self.webtoon.client.check_cancelled()?;
 
-        let mut next: Option<Id> = api.result.pagination.next;
+            let (page, cursor) = self.posts_page(Some(cursor), 100).await?;
 
-        // Add first posts
-        for post in api.result.posts {
-            posts.insert(Post::try_from((self, post))?);
+            for post in page {
+                posts.replace(post);
+            }
+
+            next = cursor;
         }
 
-        // Get rest if any
-        while let Some(cursor) = next {
+        // Adds `is_top/isPinned` info. The previous API loses this info but is easier to work with so
+        // This extra step to the other API is a one off to get only the top comment info attached to
+        // the top 3 posts.
+        for post in self.top_posts().await? {
+            posts.replace(post);
+        }
+
+        let posts: Vec<Post> = posts.into_iter().collect();
+        let mut posts = Posts { posts };
+
+        posts.sort_by_newest();
+
+        Ok(posts)
+    }
+
+    /// Retrieves a single page of posts for the episode, along with the cursor for the next page.
+    ///
+    /// Pass `None` as the `cursor` to start from the newest post, then the returned cursor to continue. When the
+    /// returned cursor is `None` there are no more pages. As a cursor is just an [`Id`], it can be stored and used
+    /// to resume a crawl later, even from another process.
+    ///
+    /// `limit` is clamped to `1..=100`, the most the API returns at once. Unlike [`Episode::posts`], [`Post::is_top`]
+    /// is always `false` for the returned posts, as that comes from a separate request.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # if let Some(episode) = webtoon.episode(1).await? {
+    /// let mut cursor = None;
+    ///
+    /// loop {
+    ///     let (posts, next) = episode.posts_page(cursor, 100).await?;
+    ///
+    ///     for post in posts {
+    ///         println!("{}", post.body().contents());
+    ///     }
+    ///
+    ///     // Persist `next` here to be able to resume from it.
+    ///     match next {
+    ///         Some(next) => cursor = Some(next),
+    ///         None => break,
+    ///     }
+    /// }
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `PostError::ClientError`: If there was an issue with the request.
+    /// - `PostError::Unexpected`: If the response could not be parsed.
+    pub async fn posts_page(
+        &self,
+        cursor: Option<Id>,
+        limit: u8,
+    ) -> Result<(Vec<Post>, Option<Id>), PostError> {
+        let response = self
+            .webtoon
+            .client
+            .get_posts_for_episode(self, cursor, limit.clamp(1, 100))
+            .await?
+            .text()
+            .await?;
+
+        let api = serde_json::from_str::<PostsResult>(&response).context(response)?;
+
+        self.cache_comments_and_replies(&api).await;
+
+        let posts = api
+            .result
+            .posts
+            .into_iter()
+            .map(|post| Post::try_from((self, post)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((posts, api.result.pagination.next))
+    }
+
+    /// Retrieves the direct (top-level) comments for the episode in the order the server ranks them.
+    ///
+    /// Where [`posts`](Episode::posts) always returns newest to oldest, this passes `sort` through to the API, so
+    /// [`PostSort::Best`] reflects the platform's own ranking rather than one approximated from upvotes.
+    ///
+    /// ### Behavior
+    ///
+    /// - The returned order is the order the pages came back in; no sorting is done afterwards.
+    /// - Like `posts`, duplicates from overlapping pages are dropped and the top comments have their pinned info attached.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error, webtoon::episode::posts::PostSort};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # if let Some(episode) = webtoon.episode(1).await? {
+    /// for post in episode.posts_sorted(PostSort::Best).await? {
+    ///     println!("{} upvotes: {}", post.upvotes(), post.body().contents());
+    /// }
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - Returns a [`PostError`] if there is an issue with the client or an unexpected error occurs during the post retrieval process.
+    pub async fn posts_sorted(&self, sort: PostSort) -> Result<Posts, PostError> {
+        let mut seen = HashSet::new();
+        let mut posts = Vec::new();
+        let mut cursor: Option<Id> = None;
+
+        loop {
+            self.webtoon.client.check_cancelled()?;
+
             let response = self
                 .webtoon
                 .client
-                .get_posts_for_episode(self, Some(cursor), 100)
+                .get_sorted_posts_for_episode(self, cursor, 100, sort)
                 .await?
                 .text()
                 .await?;
 
             let api = serde_json::from_str::<PostsResult>(&response).context(response)?;
 
+            self.cache_comments_and_replies(&api).await;
+
             for post in api.result.posts {
-                posts.replace(Post::try_from((self, post))?);
+                let post = Post::try_from((self, post))?;
+                if seen.insert(post.id) {
+                    posts.push(post);
+                }
             }
 
-            next = api.result.pagination.next;
+            cursor = api.result.pagination.next;
+
+            if cursor.is_none() {
+                break;
+            }
         }
 
-        // Adds `is_top/isPinned` info. The previous API loses this info but is easier to work with so
-        // This extra step to the other API is a one off to get only the top comment info attached to
-        // the top 3 posts.
+        for top in self.top_posts().await? {
+            if let Some(post) = posts.iter_mut().find(|post| post.id == top.id) {
+                *post = top;
+            }
+        }
+
+        Ok(Posts { posts })
+    }
+
+    /// Gets the top comments of the episode, which are the only ones that come with the pinned (`is_top`) info.
+    async fn top_posts(&self) -> Result<Vec<Post>, PostError> {
         let page_id = format!(
             "{}_{}_{}",
             self.webtoon.scope.as_single_letter(),
@@ -453,27 +1000,23 @@ impl Episode {
         let response = self
             .webtoon
             .client
-            .http
-            .get(url)
+            .get(&url)
             .header("Service-Ticket-Id", "epicom")
-            .send()
+            .send_traced()
             .await
-            .map_err(|err| ClientError::Unexpected(err.into()))?
+            .map_err(ClientError::from)?
             .text()
             .await?;
 
         let api = serde_json::from_str::<PostsResult>(&response).context(response)?;
 
-        if let Some(tops) = api.result.tops {
-            for post in tops {
-                posts.replace(Post::try_from((self, post))?);
-            }
-        }
-
-        let posts: Vec<Post> = posts.into_iter().collect();
-        let mut posts = Posts { posts };
-
-        posts.sort_by_newest();
+        let posts = api
+            .result
+            .tops
+            .unwrap_or_default()
+            .into_iter()
+            .map(|post| Post::try_from((self, post)))
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(posts)
     }
@@ -533,6 +1076,53 @@ impl Episode {
     where
         F: Fn(Post) -> Fut + Send,
         Fut: Future<Output = ()> + Send,
+    {
+        self.posts_for_each_until(move |post| {
+            let future = callback(post);
+            async move {
+                future.await;
+                ControlFlow::Continue(())
+            }
+        })
+        .await
+    }
+
+    /// Same as [`Episode::posts_for_each`], but stops as soon as `callback` returns [`ControlFlow::Break`].
+    ///
+    /// No more pages are requested after a break, so scanning for a specific post doesn't pull in every remaining
+    /// post of the episode.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{errors::Error, Type, Client};
+    /// # use std::ops::ControlFlow;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # if let Some(episode) = webtoon.episode(1).await? {
+    /// episode.posts_for_each_until(|post| async move {
+    ///     if post.poster().is_creator() {
+    ///         println!("Creator commented: {}", post.body().contents());
+    ///         return ControlFlow::Break(());
+    ///     }
+    ///
+    ///     ControlFlow::Continue(())
+    /// }).await?;
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - Returns a [`PostError`] if there is an issue with the client or an error occurs during the retrieval of posts.
+    pub async fn posts_for_each_until<F, Fut>(&self, callback: F) -> Result<(), PostError>
+    where
+        F: Fn(Post) -> Fut + Send,
+        Fut: Future<Output = ControlFlow<()>> + Send,
     {
         // Adds `is_top/isPinned` info. The previous API loses this info but is easier to work with so
         // This extra step to the other API is a one off to get only the top comment info attached to
@@ -549,12 +1139,11 @@ impl Episode {
         let response = self
             .webtoon
             .client
-            .http
-            .get(url)
+            .get(&url)
             .header("Service-Ticket-Id", "epicom")
-            .send()
+            .send_traced()
             .await
-            .map_err(|err| ClientError::Unexpected(err.into()))?
+            .map_err(ClientError::from)?
             .text()
             .await?;
 
@@ -562,7 +1151,9 @@ impl Episode {
 
         if let Some(tops) = api.result.tops {
             for post in tops {
-                callback(Post::try_from((self, post))?).await;
+                if callback(Post::try_from((self, post))?).await.is_break() {
+                    return Ok(());
+                }
             }
         }
 
@@ -576,15 +1167,21 @@ impl Episode {
 
         let api = serde_json::from_str::<PostsResult>(&response).context(response)?;
 
+        self.cache_comments_and_replies(&api).await;
+
         let mut next: Option<Id> = api.result.pagination.next;
 
         // Add first posts
         for post in api.result.posts {
-            callback(Post::try_from((self, post))?).await;
+            if callback(Post::try_from((self, post))?).await.is_break() {
+                return Ok(());
+            }
         }
 
         // Get rest if any
         while let Some(cursor) = next {
+            self.webtoon.client.check_cancelled()?;
+
             let response = self
                 .webtoon
                 .client
@@ -596,7 +1193,9 @@ impl Episode {
             let api = serde_json::from_str::<PostsResult>(&response).context(response)?;
 
             for post in api.result.posts {
-                callback(Post::try_from((self, post))?).await;
+                if callback(Post::try_from((self, post))?).await.is_break() {
+                    return Ok(());
+                }
             }
 
             next = api.result.pagination.next;
@@ -668,6 +1267,8 @@ impl Episode {
 
         let api = serde_json::from_str::<PostsResult>(&response).context(response)?;
 
+        self.cache_comments_and_replies(&api).await;
+
         let mut next: Option<Id> = api.result.pagination.next;
 
         // Add first posts
@@ -683,6 +1284,8 @@ impl Episode {
 
         // Get rest if any
         while let Some(cursor) = next {
+            self.webtoon.client.check_cancelled()?;
+
             let response = self
                 .webtoon
                 .client
@@ -771,6 +1374,8 @@ impl Episode {
 
         let api = serde_json::from_str::<PostsResult>(&response).context(response)?;
 
+        self.cache_comments_and_replies(&api).await;
+
         let mut next: Option<Id> = api.result.pagination.next;
 
         // Add first posts
@@ -786,6 +1391,8 @@ impl Episode {
 
         // Get rest if any
         while let Some(cursor) = next {
+            self.webtoon.client.check_cancelled()?;
+
             let response = self
                 .webtoon
                 .client
@@ -852,41 +1459,13 @@ impl Episode {
     ///
     /// - Returns an [`EpisodeError`] if there is a failure in fetching or processing the episode data.
     pub async fn panels(&self) -> Result<Vec<Panel>, EpisodeError> {
-        let mut page = self.page.lock().await;
-
-        if page.is_none() {
-            *page = Some(self.scrape().await?);
-        }
-
-        let panels = page
-            .as_ref()
-            .context("episode `page` should have been updated with the call to `self.scrape")?
-            .panels
-            .clone();
-
-        drop(page);
-
-        Ok(panels)
+        self.with_page(|page| page.panels.clone()).await
     }
 
     /// Returns the thumbnail URL for episode.
     pub async fn thumbnail(&self) -> Result<String, EpisodeError> {
-        let mut page = self.page.lock().await;
-
-        if page.is_none() {
-            *page = Some(self.scrape().await?);
-        }
-
-        let thumbnail = page
-            .as_ref()
-            .context("episode `page` should have been updated with the call to `self.scrape")?
-            .thumbnail
-            .as_str()
-            .to_string();
-
-        drop(page);
-
-        Ok(thumbnail)
+        self.with_page(|page| page.thumbnail.as_str().to_string())
+            .await
     }
 
     /// Returns the [`PublishedStatus`] for the episode, indicating whether the episode is published, a draft, or removed.
@@ -1098,13 +1677,7 @@ impl Episode {
 
         let token = self.webtoon.client.get_api_token().await?;
 
-        let session = self
-            .webtoon
-            .client
-            .session
-            .as_ref()
-            .map(|session| session.as_ref())
-            .unwrap_or_default();
+        let session = self.webtoon.client.session().unwrap_or_default();
 
         self.webtoon
             .client
@@ -1113,58 +1686,154 @@ impl Episode {
             .json(&body)
             .header("Service-Ticket-Id", "epicom")
             .header("Api-Token", token)
-            .header("Cookie", format!("NEO_SES={session}"))
-            .send()
+            .header("Cookie", self.webtoon.client.session_cookie(&session))
+            .send_traced()
+            .await?;
+
+        self.forget_comments_and_replies().await;
+
+        Ok(())
+    }
+
+    /// Posts a reply on the top-level comment with the given [`Id`].
+    ///
+    /// This is useful when only the `Id` of the comment is known, e.g. from a previous run, and allows replying without
+    /// having to first find the [`Post`] again. If the [`Post`] is already available, [`Post::reply`] can be used instead.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{ Client, Type, errors::Error};
+    /// # use webtoon::platform::webtoons::webtoon::episode::posts::Id;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::with_session("my-session");
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # if let Some(episode) = webtoon.episode(1).await? {
+    /// let parent: Id = "GW-epicom:0-c_843910_1-1".parse().unwrap();
+    /// episode.reply_to(parent, "I know right!", false).await?;
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `ReplyError::InvalidParent`: If `parent` is a reply itself, or is not for a post on this episode.
+    /// - `ReplyError::ClientError`: If no session was provided or there was an issue with the request.
+    pub async fn reply_to(
+        &self,
+        parent: Id,
+        body: &str,
+        is_spoiler: bool,
+    ) -> Result<(), ReplyError> {
+        if parent.is_reply()
+            || !parent.is_on_page(
+                self.webtoon.scope.as_single_letter(),
+                self.webtoon.id,
+                self.number,
+            )
+        {
+            return Err(ReplyError::InvalidParent);
+        }
+
+        self.webtoon
+            .client
+            .post_reply(self, parent, body, is_spoiler)
             .await?;
 
+        self.forget_comments_and_replies().await;
+
         Ok(())
     }
 
     /// Will download the panels of episode.
     ///
     /// This returns a [`Panels`] which offers ways to save to disk.
+    ///
+    /// ### Errors
+    ///
+    /// - `DownloadError::Corrupt`: If a panel came back cut short, so that the download can be retried instead of
+    ///   saving a broken image.
+    /// - `DownloadError::UnsupportedViewer`: If the episode uses a special viewer, like the audio or motion viewer,
+    ///   without any panels. Only panels are downloaded, so an audio track along with panels is not saved either.
+    /// - `DownloadError::ClientError`: If there was an issue with a request.
     #[cfg(feature = "download")]
-    pub async fn download(&self) -> Result<Panels, EpisodeError> {
+    pub async fn download(&self) -> Result<Panels, DownloadError> {
         use tokio::sync::Semaphore;
 
-        let mut page = self.page.lock().await;
-        if page.is_none() {
-            *page = Some(self.scrape().await?);
-        }
-
-        let mut panels = page
-            .as_ref()
-            .context("`panel_urls` should be `Some` if scrape succeeded")?
-            .panels
-            .clone();
-
-        drop(page);
+        let mut panels = self
+            .panels_for_download()
+            .await
+            .map_err(DownloadError::from_episode)?;
 
         // PERF: Download N panels at a time. Without this it will be a sequential.
         let semaphore = Semaphore::new(100);
 
-        let mut height = 0;
-        let mut width = 0;
-
         for panel in &mut panels {
             let semaphore = semaphore
                 .acquire()
                 .await
                 .context("failed to acquire sepmahore when downloading panels")?;
 
-            panel.download(&self.webtoon.client).await?;
+            let client = &self.webtoon.client;
 
-            drop(semaphore);
+            client.until_cancelled(panel.download(client)).await??;
 
-            height += panel.height;
-            width = width.max(panel.width);
+            drop(semaphore);
         }
 
-        Ok(Panels {
-            images: panels,
-            height,
-            width,
-        })
+        Ok(Panels::from_downloaded(panels))
+    }
+
+    /// Returns the panels from the episode page, scraping it if it isn't cached yet. The panels still need downloading.
+    #[cfg(feature = "download")]
+    async fn panels_for_download(&self) -> Result<Vec<Panel>, EpisodeError> {
+        self.with_page(|page| page.panels.clone()).await
+    }
+
+    /// Fetches the viewer page of the episode and returns its HTML as is, for parsing data this crate doesn't expose.
+    ///
+    /// This is the same document the other episode methods scrape, like [`Episode::title`] and [`Episode::panels`], so
+    /// it can be parsed with [`scraper`](https://docs.rs/scraper) when one of those breaks or a field is missing.
+    ///
+    /// ### Stability
+    ///
+    /// This is a low-level escape hatch. The markup is whatever webtoons.com currently serves and can change at any
+    /// time, so code parsing it can break without this crate changing. Prefer the typed methods where they exist.
+    ///
+    /// A new request is made on every call: the page cache isn't used or filled, and the response status isn't
+    /// checked, so an episode that can't be viewed returns the error page instead.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # if let Some(episode) = webtoon.episode(1).await? {
+    /// let html = episode.raw_html().await?;
+    /// println!("viewer page is {} bytes", html.len());
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `ClientError`: If the request failed or the body couldn't be read.
+    pub async fn raw_html(&self) -> Result<String, ClientError> {
+        let response = self
+            .webtoon
+            .client
+            .get_episode(&self.webtoon, self.number)
+            .await?;
+
+        Ok(response.text().await?)
     }
 
     /// Evicts the cached episode page, forcing a refetch on the next access.
@@ -1195,15 +1864,74 @@ impl Episode {
     pub async fn evict_cache(&self) {
         let mut page = self.page.lock().await;
         *page = None;
+
+        self.forget_comments_and_replies().await;
+
+        if let Some(cache) = &self.webtoon.client.page_cache {
+            cache
+                .lock()
+                .expect("page cache lock should not be poisoned")
+                .remove(&self.page_cache_key());
+        }
     }
-}
 
-// Internal use only
-impl Episode {
-    pub(crate) fn new(webtoon: &Webtoon, number: u16) -> Self {
-        Self {
+    /// Returns a JSON snapshot of the episode data that is already cached, without making any network requests.
+    ///
+    /// The snapshot has `number`, `title`, `season`, `published`, `views`, `published_status`, `ad_status`, and `thumbnail`.
+    /// Any value that hasn't been scraped yet, or is currently being scraped, is `null` rather than being fetched. This
+    /// makes it safe to use on episodes gotten from `webtoon.episodes()` where most of the page data is lazily populated.
+    ///
+    /// `published` is a millisecond timestamp, same as [`Episode::published`].
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// for episode in webtoon.episodes().await? {
+    ///     println!("{}", episode.to_json_value());
+    /// }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        let page = self.page.try_lock().ok();
+        let page = page.as_ref().and_then(|page| page.as_ref());
+
+        let title = self
+            .title
+            .try_lock()
+            .ok()
+            .and_then(|title| title.clone())
+            .or_else(|| page.map(|page| page.title.clone()));
+
+        let season = self.season.try_lock().ok().and_then(|season| *season);
+
+        json!({
+            "number": self.number,
+            "title": title,
+            "season": season,
+            "published": self.published(),
+            "views": self.views,
+            "published_status": self.published_status,
+            "ad_status": self.ad_status,
+            "thumbnail": page.map(|page| page.thumbnail.as_str()),
+        })
+    }
+}
+
+// Internal use only
+impl Episode {
+    pub(crate) fn new(webtoon: &Webtoon, number: u16) -> Self {
+        Self {
             webtoon: webtoon.clone(),
             number,
+            display_number: None,
             season: Arc::new(Mutex::new(None)),
             title: Arc::new(Mutex::new(None)),
             // NOTE: Currently there is no way to get this info from an episodes page.
@@ -1213,14 +1941,55 @@ impl Episode {
             // For now will just return None until a solution can be landed on.
             published: None,
             page: Arc::new(Mutex::new(None)),
+            comments_and_replies: Arc::new(Mutex::new(None)),
             views: None,
             ad_status: None,
             published_status: None,
         }
     }
 
+    /// Scrapes the episode page as the session user, keeping the page if they can view the episode.
+    ///
+    /// Returns `false` if they can't, like for a fast-pass episode they haven't unlocked, which webtoons.com redirects
+    /// away from the viewer. The page isn't put in the client's page cache, as it is only viewable with this session.
+    ///
+    /// A viewer page that fails to parse is an error rather than `false`, so a markup change doesn't make unlocked
+    /// episodes look locked.
+    pub(super) async fn scrape_as_session_user(&self) -> Result<bool, EpisodeError> {
+        let response = self
+            .webtoon
+            .client
+            .get_episode_with_session(&self.webtoon, self.number)
+            .await?;
+
+        if response.status() != 200 || !response.url().path().ends_with("/viewer") {
+            return Ok(false);
+        }
+
+        let text = response.text().await?;
+
+        let html = Html::parse_document(&text);
+
+        let page = Page::parse(&html, self.number)?;
+
+        *self.page.lock().await = Some(page);
+
+        Ok(true)
+    }
+
     /// Scrapes episode page, getting `note`, `length`, `title`, `thumbnail` and the urls for the panels.
-    async fn scrape<'a>(&self) -> Result<Page, EpisodeError> {
+    async fn scrape(&self) -> Result<Page, EpisodeError> {
+        if let Some(cache) = &self.webtoon.client.page_cache {
+            let cached = cache
+                .lock()
+                .expect("page cache lock should not be poisoned")
+                .get(&self.page_cache_key());
+
+            if let Some(page) = cached {
+                return Ok(page);
+            }
+        }
+
         let response = self
             .webtoon
             .client
@@ -1252,12 +2021,182 @@ impl Episode {
 
         let page = Page::parse(&html, self.number).context(text)?;
 
+        if let Some(cache) = &self.webtoon.client.page_cache {
+            cache
+                .lock()
+                .expect("page cache lock should not be poisoned")
+                .insert(self.page_cache_key(), page.clone());
+        }
+
         Ok(page)
     }
 
-    /// Returns `true` id episode exists, `false` if not. Returns `PostError` if there was an error.
-    pub(super) async fn exists(&self) -> Result<bool, PostError> {
-        posts::check_episode_exists(self).await
+    /// Runs `f` on the episode page, scraping it first if it hasn't been yet.
+    ///
+    /// Every accessor backed by the episode page goes through here, so whichever is called first fills the cache
+    /// for all the others in a single request.
+    async fn with_page<T>(&self, f: impl FnOnce(&Page) -> T) -> Result<T, EpisodeError> {
+        let mut page = self.page.lock().await;
+
+        if page.is_none() {
+            *page = Some(self.scrape().await?);
+        }
+
+        let page = page
+            .as_ref()
+            .context("episode `page` should have been updated with the call to `self.scrape`")?;
+
+        Ok(f(page))
+    }
+
+    /// Returns the thumbnail if the episode page was already scraped, without making any request.
+    #[cfg(feature = "rss")]
+    pub(crate) fn cached_thumbnail(&self) -> Option<String> {
+        let page = self.page.try_lock().ok()?;
+        page.as_ref()
+            .map(|page| page.thumbnail.as_str().to_string())
+    }
+
+    fn page_cache_key(&self) -> PageCacheKey {
+        (
+            self.webtoon.language,
+            self.webtoon.r#type(),
+            self.webtoon.id,
+            self.number,
+        )
+    }
+
+    /// Checks with webtoons.com whether this episode currently exists.
+    ///
+    /// [`Webtoon::episode`] already does this check before handing out an `Episode`, but an episode held onto for a
+    /// while may have since been taken down. This allows re-confirming it without going through `Webtoon::episode`.
+    ///
+    /// Episodes behind fast-pass or an ad wall still exist, and so return `true`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{errors::Error, Type, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # if let Some(episode) = webtoon.episode(1).await? {
+    /// if !episode.exists().await? {
+    ///     println!("episode {} was removed", episode.number());
+    /// }
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// Returns an [`EpisodeError`] if the request fails.
+    pub async fn exists(&self) -> Result<bool, EpisodeError> {
+        posts::check_episode_exists(self)
+            .await
+            .map_err(|err| match err {
+                PostError::ClientError(client_error) => EpisodeError::ClientError(client_error),
+                error => EpisodeError::Unexpected(error.into()),
+            })
+    }
+}
+
+/// Downloads the panels of every episode, sharing `concurrency` in-flight panel requests across all of them.
+///
+/// The results are in the same order as `episodes`.
+#[cfg(feature = "download")]
+pub(crate) async fn download_all(
+    client: &Client,
+    episodes: &[Episode],
+    concurrency: usize,
+) -> Result<Vec<Panels>, DownloadError> {
+    use tokio::{sync::Semaphore, task::JoinSet};
+
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+
+    let mut downloaded: Vec<Vec<Option<Panel>>> = Vec::with_capacity(episodes.len());
+    let mut tasks = JoinSet::new();
+
+    for (idx, episode) in episodes.iter().enumerate() {
+        client.check_cancelled()?;
+
+        let panels = episode
+            .panels_for_download()
+            .await
+            .map_err(DownloadError::from_episode)?;
+
+        downloaded.push(vec![None; panels.len()]);
+
+        for (number, mut panel) in panels.into_iter().enumerate() {
+            // Waiting here, rather than in the task, keeps at most `concurrency` tasks alive at once.
+            let permit = Arc::clone(&semaphore)
+                .acquire_owned()
+                .await
+                .context("failed to acquire semaphore when downloading panels")?;
+
+            let client = client.clone();
+
+            // Any task left when returning early, like after a cancellation, is aborted as `tasks` is dropped.
+            tasks.spawn(async move {
+                let result = client.until_cancelled(panel.download(&client)).await;
+                drop(permit);
+                result?.map(|()| (idx, number, panel))
+            });
+        }
+    }
+
+    while let Some(result) = tasks.join_next().await {
+        let (idx, number, panel) = result.context("panel download task failed to complete")??;
+
+        downloaded[idx][number] = Some(panel);
+    }
+
+    let panels = downloaded
+        .into_iter()
+        .map(|panels| {
+            let panels = panels
+                .into_iter()
+                .map(|panel| panel.expect("every spawned panel download should have been joined"))
+                .collect();
+            Panels::from_downloaded(panels)
+        })
+        .collect();
+
+    Ok(panels)
+}
+
+type PageCacheKey = (Language, Type, u32, u16);
+
+/// Scraped episode pages shared between every [`Episode`] made from the same [`Client`](crate::platform::webtoons::Client).
+///
+/// Keyed by the webtoon language, type, and id, along with the episode number.
+pub(crate) type PageCache = Arc<std::sync::Mutex<Lru<PageCacheKey, Page>>>;
+
+/// Turns the result of [`Episode::length`] into seconds of scrolling, with `None` for episodes without panels.
+fn read_seconds(
+    length: Result<u32, EpisodeError>,
+    pixels_per_second: u32,
+) -> Result<Option<u32>, EpisodeError> {
+    match length {
+        Ok(length) => Ok(Some(length.div_ceil(pixels_per_second.max(1)))),
+        Err(EpisodeError::NoPanelsFound | EpisodeError::UnsupportedViewer(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// The width of the widest panel and the height of all the panels stacked, for [`Episode::total_dimensions`].
+fn stacked_dimensions(
+    dimensions: Result<Vec<(u32, u32)>, EpisodeError>,
+) -> Result<Option<(u32, u32)>, EpisodeError> {
+    match dimensions {
+        Ok(dimensions) => Ok(dimensions
+            .into_iter()
+            .reduce(|(width, height), (w, h)| (width.max(w), height + h))),
+        Err(EpisodeError::NoPanelsFound | EpisodeError::UnsupportedViewer(_)) => Ok(None),
+        Err(err) => Err(err),
     }
 }
 
@@ -1351,6 +2290,77 @@ impl Episodes {
             .iter()
             .find(|__episode| __episode.number == episode)
     }
+
+    /// Returns the episode with the highest number, regardless of the order the episodes are stored in.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// if let Some(episode) = webtoon.episodes().await?.latest() {
+    ///     println!("newest episode: {}", episode.title().await?);
+    /// }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn latest(&self) -> Option<&Episode> {
+        self.episodes.iter().max_by_key(|episode| episode.number)
+    }
+
+    /// Returns the episode with the lowest number, regardless of the order the episodes are stored in.
+    #[must_use]
+    pub fn first(&self) -> Option<&Episode> {
+        self.episodes.iter().min_by_key(|episode| episode.number)
+    }
+
+    /// Returns the sum of the views of every episode, without making any requests.
+    ///
+    /// Views are only known for episodes that came from the creator dashboard, so this is `None` if any episode's
+    /// [`Episode::views`] is `None`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::with_session("session");
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// let episodes = webtoon.episodes().await?;
+    ///
+    /// if let Some(views) = episodes.total_views() {
+    ///     println!("{views} views over {} published episodes", episodes.published_count());
+    /// }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn total_views(&self) -> Option<u64> {
+        self.episodes
+            .iter()
+            .map(|episode| episode.views.map(u64::from))
+            .sum()
+    }
+
+    /// Returns how many of the episodes are [`PublishedStatus::Published`], which includes those behind ads or
+    /// fast-pass.
+    #[must_use]
+    pub fn published_count(&self) -> u16 {
+        let published = self
+            .episodes
+            .iter()
+            .filter(|episode| episode.published_status == Some(PublishedStatus::Published))
+            .count();
+
+        u16::try_from(published).expect("max episode number should fit within `u16`")
+    }
 }
 
 impl From<Vec<Episode>> for Episodes {
@@ -1362,6 +2372,40 @@ impl From<Vec<Episode>> for Episodes {
     }
 }
 
+impl Episodes {
+    /// Writes each episode as a line of JSON to `writer`, in the newline-delimited JSON format.
+    ///
+    /// Each line is the snapshot from [`Episode::to_json_value`], so no network requests are made.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// let episodes = webtoon.episodes().await?;
+    /// let file = std::fs::File::create("episodes.ndjson")?;
+    /// episodes.write_ndjson(std::io::BufWriter::new(file))?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// Returns an [`io::Error`](std::io::Error) if writing to `writer` fails.
+    pub fn write_ndjson(&self, mut writer: impl Write) -> std::io::Result<()> {
+        for episode in &self.episodes {
+            serde_json::to_writer(&mut writer, &episode.to_json_value())?;
+            writer.write_all(b"\n")?;
+        }
+
+        writer.flush()
+    }
+}
+
 impl IntoIterator for Episodes {
     type Item = Episode;
 
@@ -1373,7 +2417,10 @@ impl IntoIterator for Episodes {
 }
 
 /// Represents an [`Episode`]'s ad status.
-#[derive(Debug, Clone, Copy)]
+///
+/// Displays as the dashboard's `AD_ON` and `AD_OFF` tokens, with `NEVER` for [`AdStatus::Never`], and parses back from
+/// them. Any other dashboard status, like `PUBLISHED`, parses as [`AdStatus::Never`]. Serializes the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SerializeDisplay, DeserializeFromStr)]
 pub enum AdStatus {
     /// Episode is currently behind an ad.
     Yes,
@@ -1399,7 +2446,10 @@ pub enum AdStatus {
 ///   
 /// - `Removed`:  
 ///   The episode was previously published but has since been removed. This might happen due to takedowns, content issues, or other reasons.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Displays as `PUBLISHED`, `DRAFT`, or `REMOVED`, and parses from any of the dashboard's status tokens, so `AD_ON`
+/// parses as `Published` and `IN_REVIEW` as `Draft`. Serializes the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SerializeDisplay, DeserializeFromStr)]
 pub enum PublishedStatus {
     ///   The episode is available to the public. This includes episodes behind ad or fast-pass paywalls.
     Published,
@@ -1426,3 +2476,323 @@ impl From<DashboardStatus> for PublishedStatus {
         }
     }
 }
+
+impl fmt::Display for AdStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::Yes => "AD_ON",
+            Self::No => "AD_OFF",
+            Self::Never => "NEVER",
+        };
+
+        write!(f, "{status}")
+    }
+}
+
+impl FromStr for AdStatus {
+    type Err = ParseStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "NEVER" {
+            return Ok(Self::Never);
+        }
+
+        DashboardStatus::from_str(s)
+            .map(DashboardStatus::ad_status)
+            .map_err(|_| ParseStatusError(s.to_string()))
+    }
+}
+
+impl fmt::Display for PublishedStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::Published => "PUBLISHED",
+            Self::Draft => "DRAFT",
+            Self::Removed => "REMOVED",
+        };
+
+        write!(f, "{status}")
+    }
+}
+
+impl FromStr for PublishedStatus {
+    type Err = ParseStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DashboardStatus::from_str(s)
+            .map(Self::from)
+            .map_err(|_| ParseStatusError(s.to_string()))
+    }
+}
+
+/// A special viewer an episode can use instead of the standard one that shows panels.
+///
+/// Episodes using one of these have no panels to get, and return it in [`EpisodeError::UnsupportedViewer`].
+/// Displays as `audio` or `motion`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Viewer {
+    /// Plays a sound track, with a button to toggle it.
+    Audio,
+    /// An animated episode, shown as a motion comic.
+    Motion,
+}
+
+impl fmt::Display for Viewer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let viewer = match self {
+            Self::Audio => "audio",
+            Self::Motion => "motion",
+        };
+
+        write!(f, "{viewer}")
+    }
+}
+
+/// Represents an error that can happen when parsing a string to an [`AdStatus`] or [`PublishedStatus`].
+#[derive(Debug, Error)]
+#[error("`{0}` is not a known episode status")]
+pub struct ParseStatusError(String);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::webtoons::Client;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_round_trip_statuses_through_strings() {
+        for status in [AdStatus::Yes, AdStatus::No, AdStatus::Never] {
+            assert_eq!(status, status.to_string().parse::<AdStatus>().unwrap());
+        }
+
+        for status in [
+            PublishedStatus::Published,
+            PublishedStatus::Draft,
+            PublishedStatus::Removed,
+        ] {
+            assert_eq!(
+                status,
+                status.to_string().parse::<PublishedStatus>().unwrap()
+            );
+        }
+
+        assert_eq!(AdStatus::Never, "PUBLISHED".parse::<AdStatus>().unwrap());
+        assert_eq!(
+            PublishedStatus::Draft,
+            "IN_REVIEW".parse::<PublishedStatus>().unwrap()
+        );
+        assert!("ON".parse::<AdStatus>().is_err());
+
+        assert_eq!("\"AD_ON\"", serde_json::to_string(&AdStatus::Yes).unwrap());
+        assert_eq!(
+            PublishedStatus::Removed,
+            serde_json::from_str::<PublishedStatus>("\"REMOVED\"").unwrap()
+        );
+    }
+
+    #[test]
+    fn should_not_estimate_read_time_without_panels() {
+        assert_eq!(Some(6), read_seconds(Ok(1280), 250).unwrap());
+        assert_eq!(Some(1280), read_seconds(Ok(1280), 0).unwrap());
+        assert_eq!(
+            None,
+            read_seconds(Err(EpisodeError::UnsupportedViewer(Viewer::Audio)), 250).unwrap()
+        );
+        assert_eq!(
+            None,
+            read_seconds(Err(EpisodeError::NoPanelsFound), 250).unwrap()
+        );
+        assert!(read_seconds(Err(EpisodeError::NotViewable), 250).is_err());
+    }
+
+    #[test]
+    fn should_stack_panel_dimensions() {
+        assert_eq!(
+            Some((800, 3500)),
+            stacked_dimensions(Ok(vec![(800, 1280), (720, 1000), (800, 1220)])).unwrap()
+        );
+        assert_eq!(None, stacked_dimensions(Ok(Vec::new())).unwrap());
+        assert_eq!(
+            None,
+            stacked_dimensions(Err(EpisodeError::UnsupportedViewer(Viewer::Motion))).unwrap()
+        );
+        assert_eq!(
+            None,
+            stacked_dimensions(Err(EpisodeError::NoPanelsFound)).unwrap()
+        );
+        assert!(stacked_dimensions(Err(EpisodeError::NotViewable)).is_err());
+    }
+
+    #[tokio::test]
+    async fn should_fill_every_accessor_from_one_page() {
+        // Any request made by this client fails, so every accessor below must be served from the cached page.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let proxy = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let client = Client::from_http_client(
+            reqwest::Client::builder()
+                .proxy(reqwest::Proxy::all(proxy).unwrap())
+                .build()
+                .unwrap(),
+        );
+
+        let webtoon = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/en/canvas/testing-service/list?title_no=843910",
+            &client,
+        )
+        .unwrap();
+
+        let episode = Episode::new(&webtoon, 3);
+
+        *episode.page.lock().await = Some(Page {
+            title: "[Season 2] Episode 3".to_string(),
+            thumbnail: "https://webtoon-phinf.pstatic.net/thumb.jpg"
+                .parse()
+                .unwrap(),
+            length: 1280,
+            note: Some("Thanks for reading!".to_string()),
+            notice: None,
+            views: None,
+            panels: Vec::new(),
+        });
+
+        assert_eq!("[Season 2] Episode 3", episode.title().await.unwrap());
+        assert_eq!(Some(2), episode.season().await.unwrap());
+        assert_eq!(1280, episode.length().await.unwrap());
+        assert_eq!(
+            Some("Thanks for reading!".to_string()),
+            episode.note().await.unwrap()
+        );
+        assert_eq!(
+            "https://webtoon-phinf.pstatic.net/thumb.jpg",
+            episode.thumbnail().await.unwrap()
+        );
+        assert!(episode.panels().await.unwrap().is_empty());
+
+        *episode.comments_and_replies.lock().await = Some((12, 30));
+        assert_eq!((12, 30), episode.comments_and_replies().await.unwrap());
+
+        // Once evicted, the counts have to be fetched again, which fails here.
+        episode.evict_cache().await;
+        assert!(episode.comments_and_replies().await.is_err());
+
+        // Sanity check that an uncached episode really can't reach the network.
+        assert!(Episode::new(&webtoon, 4).title().await.is_err());
+    }
+
+    #[test]
+    fn should_make_global_id_from_language_type_webtoon_and_number() {
+        let client = Client::new();
+
+        let english = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/en/fantasy/tower-of-god/list?title_no=95",
+            &client,
+        )
+        .unwrap();
+
+        let chinese = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/zh-hant/fantasy/tower-of-god/list?title_no=95",
+            &client,
+        )
+        .unwrap();
+
+        let canvas = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/en/canvas/tower-of-god/list?title_no=95",
+            &client,
+        )
+        .unwrap();
+
+        assert_eq!(
+            "webtoons:en:original:95:42",
+            Episode::new(&english, 42).global_id()
+        );
+        assert_eq!(
+            "webtoons:zh-hant:original:95:42",
+            Episode::new(&chinese, 42).global_id()
+        );
+        assert_eq!(
+            "webtoons:en:canvas:95:42",
+            Episode::new(&canvas, 42).global_id()
+        );
+    }
+
+    #[test]
+    fn should_find_latest_and_first_in_any_order() {
+        let webtoon = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/en/canvas/testing-service/list?title_no=843910",
+            &Client::new(),
+        )
+        .unwrap();
+
+        let episodes = Episodes::from(
+            [3, 7, 1, 5]
+                .into_iter()
+                .map(|number| Episode::new(&webtoon, number))
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(Some(7), episodes.latest().map(Episode::number));
+        assert_eq!(Some(1), episodes.first().map(Episode::number));
+
+        let empty = Episodes::from(Vec::new());
+        assert!(empty.latest().is_none());
+        assert!(empty.first().is_none());
+    }
+
+    #[test]
+    fn should_total_views_only_when_all_are_known() {
+        let webtoon = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/en/canvas/testing-service/list?title_no=843910",
+            &Client::new(),
+        )
+        .unwrap();
+
+        let episode = |number, views, status| {
+            let mut episode = Episode::new(&webtoon, number);
+            episode.views = views;
+            episode.published_status = Some(status);
+            episode
+        };
+
+        let episodes = Episodes::from(vec![
+            episode(1, Some(u32::MAX), PublishedStatus::Published),
+            episode(2, Some(10), PublishedStatus::Draft),
+            episode(3, Some(5), PublishedStatus::Published),
+        ]);
+
+        assert_eq!(Some(u64::from(u32::MAX) + 15), episodes.total_views());
+        assert_eq!(2, episodes.published_count());
+
+        let episodes = Episodes::from(vec![
+            episode(1, Some(3), PublishedStatus::Published),
+            episode(2, None, PublishedStatus::Published),
+        ]);
+
+        assert_eq!(None, episodes.total_views());
+    }
+
+    #[test]
+    fn should_snapshot_without_scraping() {
+        let webtoon = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/en/canvas/testing-service/list?title_no=843910",
+            &Client::new(),
+        )
+        .unwrap();
+
+        let mut episode = Episode::new(&webtoon, 3);
+        episode.views = Some(42);
+        episode.published_status = Some(PublishedStatus::Draft);
+
+        let episodes = Episodes::from(vec![episode]);
+
+        let mut ndjson = Vec::new();
+        episodes.write_ndjson(&mut ndjson).unwrap();
+
+        assert_eq!(
+            String::from_utf8(ndjson).unwrap(),
+            "{\"ad_status\":null,\"number\":3,\"published\":null,\"published_status\":\"DRAFT\",\"season\":null,\"thumbnail\":null,\"title\":null,\"views\":42}\n"
+        );
+    }
+}
@@ -6,7 +6,8 @@ use url::Url;
 
 use self::panels::Panel;
 
-use super::EpisodeError;
+use super::{EpisodeError, Viewer};
+use crate::platform::webtoons::cdn;
 
 #[derive(Debug, Clone)]
 pub struct Page {
@@ -14,6 +15,8 @@ pub struct Page {
     pub(super) thumbnail: Url,
     pub(super) length: u32,
     pub(super) note: Option<String>,
+    pub(super) notice: Option<String>,
+    pub(super) views: Option<u32>,
     pub(super) panels: Vec<Panel>,
 }
 
@@ -24,6 +27,8 @@ impl Page {
             thumbnail: thumbnail(html, episode).context("Episode thumbnail failed to be parsed")?,
             length: length(html).context("Episode length failed to be parsed")?,
             note: note(html).context("Episode creator note failed to be parsed")?,
+            notice: notice(html),
+            views: views(html),
             panels: panels::from_html(html, episode)
                 .context("Episode panel urls failed to be parsed")?,
         })
@@ -52,24 +57,36 @@ fn length(html: &Html) -> Result<u32, EpisodeError> {
     let mut length = 0;
 
     for img in html.select(&selector) {
-        length += img
-            .value()
-            .attr("height")
-            .context("`height` is missing, `img._images` should always have one")?
-            .split('.')
-            .next()
-            .context("`height` attribute should be a float")?
-            .parse::<u32>()
-            .map_err(|err| EpisodeError::Unexpected(err.into()))?;
+        let (width, height) = panels::dimensions(&img)?;
+
+        if panels::is_panel(&img, width, height) {
+            length += height;
+        }
     }
 
     if length == 0 {
-        return Err(EpisodeError::NoPanelsFound);
+        return Err(match viewer(html) {
+            Some(viewer) => EpisodeError::UnsupportedViewer(viewer),
+            None => EpisodeError::NoPanelsFound,
+        });
     }
 
     Ok(length)
 }
 
+/// Returns the kind of special viewer the episode uses, if it isn't the standard one that shows panels.
+fn viewer(html: &Html) -> Option<Viewer> {
+    let viewers = [
+        ("button#soundControl", Viewer::Audio),
+        ("[id*=motiontoon], [class*=motiontoon]", Viewer::Motion),
+    ];
+
+    viewers.into_iter().find_map(|(selector, viewer)| {
+        let selector = Selector::parse(selector).expect("viewer selector should be valid");
+        html.select(&selector).next().map(|_| viewer)
+    })
+}
+
 fn note(html: &Html) -> Result<Option<String>, EpisodeError> {
     let selector = Selector::parse(r".creator_note>.author_text") //
         .expect("`.creator_note>.author_text` should be a valid selector");
@@ -87,6 +104,52 @@ fn note(html: &Html) -> Result<Option<String>, EpisodeError> {
     Ok(Some(note))
 }
 
+/// The announcement webtoons.com puts above the panels, like for a hiatus or schedule change.
+///
+/// Unlike the creator note, this is written by webtoons.com, and most episodes don't have one.
+fn notice(html: &Html) -> Option<String> {
+    let selector =
+        Selector::parse(r"div.viewer_notice, div.notice_area") //
+            .expect("`div.viewer_notice, div.notice_area` should be a valid selector");
+
+    let notice = html
+        .select(&selector)
+        .next()?
+        .text()
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if notice.is_empty() {
+        return None;
+    }
+
+    Some(notice)
+}
+
+/// The view count some language sites show under the episode title.
+///
+/// Counts that are abbreviated, like `1.2M`, are not exact, so they are treated the same as a missing count.
+fn views(html: &Html) -> Option<u32> {
+    let selector = Selector::parse(r"div.subj_info .view_count") //
+        .expect("`div.subj_info .view_count` should be a valid selector");
+
+    let text = html.select(&selector).next()?.text().collect::<String>();
+
+    let digits: String = text
+        .trim()
+        .chars()
+        .filter(|ch| !matches!(ch, ',' | '.' | ' ' | '\u{a0}'))
+        .collect();
+
+    if digits.is_empty() || !digits.chars().all(|ch| ch.is_ascii_digit()) {
+        return None;
+    }
+
+    digits.parse().ok()
+}
+
 fn thumbnail(html: &Html, episode: u16) -> Result<Url, EpisodeError> {
     let selector =
         Selector::parse(r"div.episode_lst>div.episode_cont>ul>li") //
@@ -118,13 +181,69 @@ fn thumbnail(html: &Html, episode: u16) -> Result<Url, EpisodeError> {
 
         let mut thumbnail = Url::parse(url).map_err(|err| EpisodeError::Unexpected(err.into()))?;
 
-        thumbnail
-            // This host doesn't need a `referer` header to see the image.
-            .set_host(Some("swebtoon-phinf.pstatic.net"))
-            .expect("`swebtoon-phinf.pstatic.net` should be a valid host");
+        cdn::rewrite_host(&mut thumbnail);
 
         return Ok(thumbnail);
     }
 
     Err(EpisodeError::NoThumbnailFound)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_report_unsupported_viewer_without_panels() {
+        let html = Html::parse_document(
+            r#"<div class="viewer_lst"><button id="soundControl" type="button">Sound</button></div>"#,
+        );
+        assert!(matches!(
+            length(&html),
+            Err(EpisodeError::UnsupportedViewer(Viewer::Audio))
+        ));
+
+        let html = Html::parse_document(r#"<div class="viewer_lst"></div>"#);
+        assert!(matches!(length(&html), Err(EpisodeError::NoPanelsFound)));
+    }
+
+    #[test]
+    fn should_keep_notice_apart_from_note() {
+        let html = Html::parse_document(
+            r#"<div class="viewer_notice"><p>This series is on hiatus</p>
+<p>It will return on March 3</p></div><div class="creator_note"><p class="author_text">See you soon!</p></div>"#,
+        );
+
+        assert_eq!(
+            Some("This series is on hiatus\nIt will return on March 3".to_string()),
+            notice(&html)
+        );
+        assert_eq!(Some("See you soon!".to_string()), note(&html).unwrap());
+
+        let html = Html::parse_document(
+            r#"<div class="viewer_notice"><p>Q&amp;amp;A next week</p></div>"#,
+        );
+        assert_eq!(Some("Q&amp;A next week".to_string()), notice(&html));
+
+        let html = Html::parse_document(r#"<div class="viewer_notice"> </div>"#);
+        assert_eq!(None, notice(&html));
+    }
+
+    #[test]
+    fn should_only_take_exact_view_counts() {
+        let page = |views: &str| {
+            Html::parse_document(&format!(
+                r#"<div class="subj_info"><h1 class="subj_episode">Ep. 1</h1><span class="view_count">{views}</span></div>"#
+            ))
+        };
+
+        assert_eq!(Some(1_234_567), views(&page("1,234,567")));
+        assert_eq!(Some(1_234_567), views(&page("1.234.567")));
+        assert_eq!(None, views(&page("1.2M")));
+        assert_eq!(
+            None,
+            views(&Html::parse_document(r#"<div class="subj_info"></div>"#))
+        );
+    }
+}
@@ -1,14 +1,19 @@
-use crate::platform::webtoons::webtoon::episode::EpisodeError;
+use crate::platform::webtoons::{cdn, webtoon::episode::EpisodeError};
 use anyhow::{anyhow, Context};
-use scraper::{Html, Selector};
+use scraper::{ElementRef, Html, Selector};
 use url::Url;
 
+#[cfg(feature = "download")]
+use crate::platform::webtoons::client::trace::Traced;
 #[cfg(feature = "download")]
 use crate::platform::webtoons::{errors::DownloadError, Client};
 #[cfg(feature = "download")]
-use image::{GenericImageView, ImageFormat, RgbaImage};
+use image::{codecs::jpeg::JpegEncoder, RgbImage};
 #[cfg(feature = "download")]
-use std::path::Path;
+use std::{
+    io::{BufWriter, Write},
+    path::Path,
+};
 #[cfg(feature = "download")]
 use tokio::{fs::File, io::AsyncWriteExt};
 
@@ -19,15 +24,12 @@ pub struct Panel {
 
     #[cfg(feature = "download")]
     pub(in crate::platform::webtoons::webtoon::episode) episode: u16,
-    #[cfg(feature = "download")]
     pub(in crate::platform::webtoons::webtoon::episode) number: u16,
     #[cfg(feature = "download")]
     pub(in crate::platform::webtoons::webtoon::episode) ext: String,
     #[cfg(feature = "download")]
     pub(in crate::platform::webtoons::webtoon::episode) bytes: Vec<u8>,
-    #[cfg(feature = "download")]
     pub(in crate::platform::webtoons::webtoon::episode) height: u32,
-    #[cfg(feature = "download")]
     pub(in crate::platform::webtoons::webtoon::episode) width: u32,
 }
 
@@ -37,25 +39,153 @@ impl Panel {
         self.url.as_str()
     }
 
+    /// Returns the position of the panel in the episode.
+    ///
+    /// Panels are numbered from 1 in reading order, with no gaps, as decorative images on the page are not counted as
+    /// panels.
+    #[must_use]
+    pub fn number(&self) -> u16 {
+        self.number
+    }
+
+    /// Returns the `(width, height)` of the panel in pixels.
+    ///
+    /// These come from the episode page itself, so are available without downloading the panel.
+    #[must_use]
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
     #[cfg(feature = "download")]
     pub(in crate::platform::webtoons::webtoon::episode) async fn download(
         &mut self,
         client: &Client,
-    ) -> Result<(), EpisodeError> {
-        let bytes = client
-            .http
-            .get(self.url.as_str())
-            .send()
-            .await?
-            .bytes()
-            .await?;
+    ) -> Result<(), DownloadError> {
+        let mut request = client.http.get(self.url.as_str());
+
+        // Panels on hosts that couldn't be rewritten are only served with a `Referer`.
+        if cdn::needs_referer(&self.url) {
+            request = request.header("Referer", cdn::REFERER);
+        }
+
+        let response = request.send_traced().await?;
+        let expected = response.content_length();
+        let bytes = response.bytes().await?;
 
         self.bytes = bytes.to_vec();
 
+        if !self.is_intact(expected) {
+            return Err(DownloadError::Corrupt {
+                episode: self.episode,
+                panel: self.number,
+            });
+        }
+
         Ok(())
     }
 }
 
+#[cfg(feature = "download")]
+impl Panel {
+    /// Checks the downloaded bytes for signs of a cut off transfer.
+    ///
+    /// The length must match the `Content-Length`, when there was one, the image header must be readable, and JPEG
+    /// and PNG images must end with their end marker, as these are lost first when a transfer is cut short.
+    fn is_intact(&self, expected: Option<u64>) -> bool {
+        if expected.is_some_and(|expected| expected != self.bytes.len() as u64) {
+            return false;
+        }
+
+        let Ok(format) = image::guess_format(&self.bytes) else {
+            return false;
+        };
+
+        let complete = match format {
+            image::ImageFormat::Jpeg => self.bytes.ends_with(&[0xFF, 0xD9]),
+            image::ImageFormat::Png => self
+                .bytes
+                .ends_with(&[0x49, 0x45, 0x4E, 0x44, 0xAE, 0x42, 0x60, 0x82]),
+            _ => true,
+        };
+
+        complete && self.decoded_dimensions().is_ok()
+    }
+
+    /// Reads the `(width, height)` from the image header, without decoding the whole image.
+    fn decoded_dimensions(&self) -> Result<(u32, u32), DownloadError> {
+        let dimensions = image::ImageReader::new(std::io::Cursor::new(&self.bytes))
+            .with_guessed_format()?
+            .into_dimensions()
+            .context("failed to read image dimensions")?;
+
+        Ok(dimensions)
+    }
+
+    fn decode(&self) -> Result<RgbImage, DownloadError> {
+        let image = image::load_from_memory(&self.bytes) //
+            .context("failed to load image from memory")?;
+
+        Ok(image.into_rgb8())
+    }
+}
+
+/// Panels are drawn for the width of the viewer, so anything narrower is a decoration, like a divider or an icon,
+/// rather than part of the episode.
+const MIN_PANEL_WIDTH: u32 = 100;
+
+/// Returns whether an `img._images` is part of the episode, rather than a decoration the viewer puts between panels.
+///
+/// Panels are always lazy loaded from `data-url`, and must have a sensible size.
+pub(super) fn is_panel(img: &ElementRef<'_>, width: u32, height: u32) -> bool {
+    img.value().attr("data-url").is_some() && width >= MIN_PANEL_WIDTH && height > 0
+}
+
+/// Reads the `(width, height)` of an `img._images`.
+pub(super) fn dimensions(img: &ElementRef<'_>) -> Result<(u32, u32), EpisodeError> {
+    let height = img
+        .value()
+        .attr("height")
+        .context("`height` is missing, `img._images` should always have one")?
+        .split('.')
+        .next()
+        .context("`height` attribute should be a float")?
+        .parse::<u32>()
+        .map_err(|err| EpisodeError::Unexpected(err.into()))?;
+
+    let width = img
+        .value()
+        .attr("width")
+        .context("`width` is missing, `img._images` should always have one")?
+        .split('.')
+        .next()
+        .context("`width` attribute should be a float")?
+        .parse::<u32>()
+        .map_err(|err| EpisodeError::Unexpected(err.into()))?;
+
+    Ok((width, height))
+}
+
+/// Gets the file extension from the last segment of the panel url, lowercased, with `jpeg` shortened to `jpg`.
+///
+/// Panel paths can have more than one `.`, like `/20240101_1/panel_001.v2.jpg`, so only the part after the last one
+/// is used. The query, like `?type=q90`, is not part of the path.
+#[cfg(feature = "download")]
+fn extension(url: &Url) -> Option<String> {
+    let (_, ext) = url.path_segments()?.next_back()?.rsplit_once('.')?;
+
+    if ext.is_empty() {
+        return None;
+    }
+
+    let ext = ext.to_ascii_lowercase();
+
+    if ext == "jpeg" {
+        return Some("jpg".to_string());
+    }
+
+    Some(ext)
+}
+
 #[allow(unused, reason = "not all features use `episode`")]
 pub(super) fn from_html(html: &Html, episode: u16) -> Result<Vec<Panel>, EpisodeError> {
     let selector = Selector::parse(r"img._images") //
@@ -63,29 +193,12 @@ pub(super) fn from_html(html: &Html, episode: u16) -> Result<Vec<Panel>, Episode
 
     let mut panels = Vec::new();
 
-    #[allow(unused, reason = "not all features use `number`")]
-    for (number, img) in html.select(&selector).enumerate() {
-        #[cfg(feature = "download")]
-        let height = img
-            .value()
-            .attr("height")
-            .context("`height` is missing, `img._images` should always have one")?
-            .split('.')
-            .next()
-            .context("`height` attribute should be a float")?
-            .parse::<u32>()
-            .map_err(|err| EpisodeError::Unexpected(err.into()))?;
+    for img in html.select(&selector) {
+        let (width, height) = dimensions(&img)?;
 
-        #[cfg(feature = "download")]
-        let width = img
-            .value()
-            .attr("width")
-            .context("`width` is missing, `img._images` should always have one")?
-            .split('.')
-            .next()
-            .context("`width` attribute should be a float")?
-            .parse::<u32>()
-            .map_err(|err| EpisodeError::Unexpected(err.into()))?;
+        if !is_panel(&img, width, height) {
+            continue;
+        }
 
         let url = img
             .value()
@@ -94,29 +207,23 @@ pub(super) fn from_html(html: &Html, episode: u16) -> Result<Vec<Panel>, Episode
 
         let mut url = Url::parse(url).map_err(|err| EpisodeError::Unexpected(err.into()))?;
 
-        url.set_host(Some("swebtoon-phinf.pstatic.net"))
-            .expect("`swebtoon-phinf.pstatic.net` should be a valid host");
+        cdn::rewrite_host(&mut url);
 
         #[cfg(feature = "download")]
-        let ext = url
-            .path()
-            .split('.')
-            .nth(1)
-            .with_context(|| format!("`{url}` should end in an extension but didn't"))?
-            .to_string();
+        let ext = extension(&url)
+            .with_context(|| format!("`{url}` should end in an extension but didn't"))?;
+
+        // Counted from the panels kept so far, so that skipped decorations leave no gaps.
+        let number = u16::try_from(panels.len() + 1)
+            .context("there shouldn't be more than 65,536 panels for an episode")?;
 
         panels.push(Panel {
             url,
 
             #[cfg(feature = "download")]
             episode,
-            // Enumerate starts at 0. +1 so that it starts at one.
-            #[cfg(feature = "download")]
-            number: u16::try_from(number + 1)
-                .context("there shouldn't be more than 65,536 panels for an episode")?,
-            #[cfg(feature = "download")]
+            number,
             height,
-            #[cfg(feature = "download")]
             width,
             #[cfg(feature = "download")]
             ext,
@@ -134,6 +241,30 @@ pub(super) fn from_html(html: &Html, episode: u16) -> Result<Vec<Panel>, Episode
     Ok(panels)
 }
 
+#[cfg(feature = "download")]
+/// The image format used when saving panels as a single image with [`Panels::save_single_as`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Lossless PNG.
+    #[default]
+    Png,
+    /// Lossy JPEG, with a `quality` from 1 to 100.
+    Jpeg {
+        /// Quality of the encoded image, from 1 to 100.
+        quality: u8,
+    },
+}
+
+#[cfg(feature = "download")]
+impl Format {
+    const fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg { .. } => "jpg",
+        }
+    }
+}
+
 #[cfg(feature = "download")]
 /// Represents all the panels for an episode.
 #[derive(Debug, Clone)]
@@ -145,12 +276,41 @@ pub struct Panels {
 
 #[cfg(feature = "download")]
 impl Panels {
+    /// Stacks already downloaded panels, in order, into a `Panels`.
+    pub(in crate::platform::webtoons::webtoon::episode) fn from_downloaded(
+        images: Vec<Panel>,
+    ) -> Self {
+        let height = images.iter().map(|panel| panel.height).sum();
+        let width = images
+            .iter()
+            .map(|panel| panel.width)
+            .max()
+            .unwrap_or_default();
+
+        Self {
+            images,
+            height,
+            width,
+        }
+    }
+
+    /// Returns the `(width, height)` of the episode when all panels are stacked vertically.
+    ///
+    /// The width is that of the widest panel, and the height is the sum of all the panel heights, as given by the
+    /// `width` and `height` attributes on the episode page. [`Panels::save_single`] sizes its image from the
+    /// downloaded images instead, as these attributes don't always match them, so the saved image can differ.
+    #[must_use]
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
     /// Saves all the panels of an episode as a single long image file in PNG format.
     ///
+    /// This is the same as calling [`Panels::save_single_as`] with [`Format::Png`].
+    ///
     /// # Behavior
     ///
-    /// - Combines all panels of the episode vertically into one long image.
-    /// - The output image is always saved as a PNG file, even if the original panels are in a different format (e.g., JPEG), due to JPEG's limitations.
+    /// - Combines all panels of the episode vertically into one long image, saved as `EPISODE_NUMBER.png`.
     /// - If the directory specified by `path` does not exist, it will be created along with any required parent directories.
     ///
     /// # Parameters
@@ -161,6 +321,49 @@ impl Panels {
     ///
     /// - Returns a [`DownloadError`] if any issues arise during directory creation, image creation, or writing the combined image to disk.
     pub async fn save_single<P>(&self, path: P) -> Result<(), DownloadError>
+    where
+        P: AsRef<Path> + Send,
+    {
+        self.save_single_as(path, Format::Png).await
+    }
+
+    /// Saves all the panels of an episode as a single long image file in the given [`Format`].
+    ///
+    /// # Behavior
+    ///
+    /// - Combines all panels of the episode vertically into one long image, saved as `EPISODE_NUMBER.EXT`, where the
+    ///   extension matches the `format`.
+    /// - Panels are decoded into RGB, keeping the color space of the source JPEG panels rather than adding an alpha channel.
+    /// - Panels narrower than the widest panel are padded on the right with white.
+    /// - For [`Format::Png`], panels are decoded and written one at a time, so only a single decoded panel is held in
+    ///   memory, no matter how long the episode is.
+    /// - For [`Format::Jpeg`], the whole image has to be held in memory for the encoder, and JPEG can't be taller or wider
+    ///   than 65,535 pixels.
+    /// - If the directory specified by `path` does not exist, it will be created along with any required parent directories.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # use webtoon::platform::webtoons::webtoon::episode::Format;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # if let Some(episode) = webtoon.episode(1).await? {
+    /// let panels = episode.download().await?;
+    /// panels.save_single_as("panels", Format::Jpeg { quality: 90 }).await?;
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - Returns [`DownloadError::ImageTooLarge`] if the image is too large for the chosen format.
+    /// - Returns a [`DownloadError`] if any issues arise during directory creation, decoding the panels, or writing the combined image to disk.
+    pub async fn save_single_as<P>(&self, path: P, format: Format) -> Result<(), DownloadError>
     where
         P: AsRef<Path> + Send,
     {
@@ -168,37 +371,97 @@ impl Panels {
 
         tokio::fs::create_dir_all(path).await?;
 
-        let ext = &self.images[0].ext;
         let episode = self.images[0].episode;
-        let width = self.width;
-        let height = self.height;
+        let path = path
+            .join(episode.to_string())
+            .with_extension(format.extension());
+
+        // Cloning the bytes is needed to move them into the blocking task, but are still encoded and so are much
+        // smaller than the decoded image.
+        let panels = self.clone();
+
+        tokio::task::spawn_blocking(move || {
+            // The `width` and `height` attributes on the page are only hints for how it should be displayed, and
+            // don't always match the actual image.
+            let (mut width, mut height) = (0, 0);
+            for panel in &panels.images {
+                let (w, h) = panel.decoded_dimensions()?;
+                width = width.max(w);
+                height += h;
+            }
 
-        let path = path.join(episode.to_string()).with_extension(ext);
+            match format {
+                Format::Png => panels.write_png(&path, width, height),
+                Format::Jpeg { quality } => panels.write_jpeg(&path, width, height, quality),
+            }
+        })
+        .await
+        .context("Failed `spawn_blocking`")??;
 
-        File::create(&path)
-            .await
-            .context("failed to create download file")?;
+        Ok(())
+    }
 
-        let mut single = RgbaImage::new(width, height);
+    fn write_png(&self, path: &Path, width: u32, height: u32) -> Result<(), DownloadError> {
+        let file = std::fs::File::create(path).context("failed to create download file")?;
 
-        let mut offset = 0;
+        let mut encoder = png::Encoder::new(BufWriter::new(file), width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
 
-        for panel in &self.images {
-            let bytes = panel.bytes.as_slice();
+        let mut writer = encoder
+            .write_header()
+            .context("failed to write PNG header")?;
+
+        let mut stream = writer
+            .stream_writer()
+            .context("failed to create PNG stream writer")?;
 
-            let image = image::load_from_memory(bytes) //
-                .context("failed to load image from memory")?;
+        let row_len = width as usize * 3;
+        let mut row = vec![u8::MAX; row_len];
+
+        for panel in &self.images {
+            let image = panel.decode()?;
+            let panel_row_len = image.width() as usize * 3;
 
-            for (x, y, pixels) in image.pixels() {
-                single.put_pixel(x, y + offset, pixels);
+            for pixels in image.as_raw().chunks_exact(panel_row_len) {
+                row[..panel_row_len].copy_from_slice(pixels);
+                row[panel_row_len..].fill(u8::MAX);
+                stream.write_all(&row)?;
             }
+        }
+
+        stream.finish().context("failed to finish writing PNG")?;
+
+        Ok(())
+    }
+
+    fn write_jpeg(
+        &self,
+        path: &Path,
+        width: u32,
+        height: u32,
+        quality: u8,
+    ) -> Result<(), DownloadError> {
+        if width > u32::from(u16::MAX) || height > u32::from(u16::MAX) {
+            return Err(DownloadError::ImageTooLarge { width, height });
+        }
+
+        let mut single = RgbImage::from_pixel(width, height, image::Rgb([u8::MAX; 3]));
+
+        let mut offset = 0;
+
+        for panel in &self.images {
+            let image = panel.decode()?;
+
+            image::imageops::replace(&mut single, &image, 0, i64::from(offset));
 
             offset += image.height();
         }
 
-        tokio::task::spawn_blocking(move || single.save_with_format(path, ImageFormat::Png))
-            .await
-            .context("Failed `spawn_blocking`")?
+        let file = std::fs::File::create(path).context("failed to create download file")?;
+
+        JpegEncoder::new_with_quality(BufWriter::new(file), quality.clamp(1, 100))
+            .encode_image(&single)
             .context("Failed to save image to disk")?;
 
         Ok(())
@@ -243,3 +506,126 @@ impl Panels {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "download"))]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn panel(number: u16, width: u32, height: u32) -> Panel {
+        let mut bytes = Vec::new();
+        RgbImage::from_pixel(width, height, image::Rgb([0, 0, 0]))
+            .write_to(
+                &mut std::io::Cursor::new(&mut bytes),
+                image::ImageFormat::Png,
+            )
+            .unwrap();
+
+        Panel {
+            url: Url::parse("https://swebtoon-phinf.pstatic.net/panel.png").unwrap(),
+            episode: 1,
+            number,
+            ext: "png".to_string(),
+            bytes,
+            height,
+            width,
+        }
+    }
+
+    #[test]
+    fn should_take_extension_from_last_path_segment() {
+        let extension = |url: &str| extension(&Url::parse(url).unwrap());
+
+        assert_eq!(
+            Some("jpg".to_string()),
+            extension("https://swebtoon-phinf.pstatic.net/20240101_1/panel_001.v2.JPEG?type=q90")
+        );
+        assert_eq!(
+            Some("png".to_string()),
+            extension("https://swebtoon-phinf.pstatic.net/2024.01.01/panel.png?type=q90.jpg")
+        );
+        assert_eq!(
+            None,
+            extension("https://swebtoon-phinf.pstatic.net/2024.01.01/panel")
+        );
+    }
+
+    #[test]
+    fn should_reject_truncated_panels() {
+        let mut panel = panel(1, 4, 4);
+        let length = panel.bytes.len() as u64;
+
+        assert!(panel.is_intact(Some(length)));
+        assert!(panel.is_intact(None));
+        assert!(!panel.is_intact(Some(length + 10)));
+
+        panel.bytes.truncate(panel.bytes.len() - 4);
+        assert!(!panel.is_intact(None));
+
+        panel.bytes.truncate(4);
+        assert!(!panel.is_intact(None));
+    }
+
+    #[test]
+    fn should_number_panels_without_decorations() {
+        let html = Html::parse_document(
+            r#"<img class="_images" data-url="https://webtoon-phinf.pstatic.net/1/001.jpg" width="800.0" height="1280.0"><img class="_images" data-url="https://webtoon-phinf.pstatic.net/1/divider.png" width="40.0" height="40.0"><img class="_images" src="https://webtoons-static.pstatic.net/image/ad.png" width="800.0" height="200.0"><img class="_images" data-url="https://webtoon-phinf.pstatic.net/1/002.jpg" width="800.0" height="640.0">"#,
+        );
+
+        let panels = from_html(&html, 1).unwrap();
+
+        assert_eq!(
+            vec![1, 2],
+            panels.iter().map(Panel::number).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            "https://swebtoon-phinf.pstatic.net/1/002.jpg",
+            panels[1].url()
+        );
+    }
+
+    #[test]
+    fn should_keep_host_of_unknown_cdn() {
+        let html = Html::parse_document(
+            r#"<img class="_images" data-url="https://webtoon-phinf.pstatic.net/1/001.jpg" width="800.0" height="1280.0"><img class="_images" data-url="https://cdn.webtoons.com/1/002.jpg" width="800.0" height="640.0">"#,
+        );
+
+        let panels = from_html(&html, 1).unwrap();
+
+        assert_eq!(
+            "https://swebtoon-phinf.pstatic.net/1/001.jpg",
+            panels[0].url()
+        );
+        assert_eq!("https://cdn.webtoons.com/1/002.jpg", panels[1].url());
+        assert!(cdn::needs_referer(&panels[1].url));
+    }
+
+    #[tokio::test]
+    async fn should_stitch_panels_in_each_format() {
+        let panels = Panels {
+            images: vec![panel(1, 20, 10), panel(2, 10, 5)],
+            height: 15,
+            width: 20,
+        };
+
+        let dir = std::env::temp_dir().join(format!("webtoon-stitch-{}", std::process::id()));
+
+        panels.save_single_as(&dir, Format::Png).await.unwrap();
+        panels
+            .save_single_as(&dir, Format::Jpeg { quality: 90 })
+            .await
+            .unwrap();
+
+        let png = image::open(dir.join("1.png")).unwrap().into_rgb8();
+        assert_eq!(png.dimensions(), (20, 15));
+        // Narrower second panel is padded with white.
+        assert_eq!(png.get_pixel(5, 12), &image::Rgb([0, 0, 0]));
+        assert_eq!(png.get_pixel(15, 12), &image::Rgb([255, 255, 255]));
+
+        let jpeg = image::open(dir.join("1.jpg")).unwrap();
+        assert_eq!(jpeg.width(), 20);
+        assert_eq!(jpeg.height(), 15);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
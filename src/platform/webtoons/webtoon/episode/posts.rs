@@ -3,8 +3,15 @@
 use anyhow::{anyhow, bail, Context};
 use chrono::{DateTime, Utc};
 use core::fmt;
+use serde::Serialize;
 use serde_json::json;
-use std::{cmp::Ordering, collections::HashSet, hash::Hash, str::FromStr, sync::Arc};
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    str::FromStr,
+    sync::Arc,
+};
 use thiserror::Error;
 use tokio::sync::RwLock;
 
@@ -120,10 +127,13 @@ pub use crate::platform::webtoons::client::posts::Id;
 use crate::{
     platform::webtoons::{
         self,
-        client::posts::{Count, PostsResult, Section},
+        client::{
+            posts::{Count, PostResult, PostsResult, Section},
+            trace::Traced,
+        },
         errors::{ClientError, PostError, PosterError, ReplyError},
         meta::Scope,
-        Webtoon,
+        Language, Type, Webtoon,
     },
     private::Sealed,
 };
@@ -152,6 +162,71 @@ impl Posts {
         self.into_iter().filter(predicate)
     }
 
+    /// Removes the posts that were deleted, but are still shown as "This comment has been deleted" because they have
+    /// replies.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # if let Some(episode) = webtoon.episode(1).await? {
+    /// for post in episode.posts().await?.without_deleted() {
+    ///     println!("{}", post.body().contents());
+    /// }
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn without_deleted(mut self) -> Self {
+        self.posts.retain(|post| !post.is_deleted);
+        self
+    }
+
+    /// Keeps only the posts that were deleted, but are still shown because they have replies.
+    ///
+    /// The opposite of [`Posts::without_deleted`].
+    #[must_use]
+    pub fn only_deleted(mut self) -> Self {
+        self.posts.retain(|post| post.is_deleted);
+        self
+    }
+
+    /// Sums the super likes each poster gave across these posts, from the most given to the least.
+    ///
+    /// Posters are grouped by [`Poster::cuid`], and those that gave no super likes are left out. Posters with the same
+    /// total are ordered by username, so the order is the same between runs.
+    #[must_use]
+    pub fn super_likers(&self) -> Vec<(Poster, u32)> {
+        let mut totals: HashMap<&str, (&Poster, u32)> = HashMap::new();
+
+        for poster in self.posts.iter().map(Post::poster) {
+            let Some(super_like) = poster.super_like.filter(|amount| *amount > 0) else {
+                continue;
+            };
+
+            totals.entry(&poster.cuid).or_insert((poster, 0)).1 += super_like;
+        }
+
+        let mut super_likers: Vec<(Poster, u32)> = totals
+            .into_values()
+            .map(|(poster, total)| (poster.clone(), total))
+            .collect();
+
+        super_likers.sort_unstable_by(|(a, a_total), (b, b_total)| {
+            b_total
+                .cmp(a_total)
+                .then_with(|| a.username.cmp(&b.username))
+        });
+
+        super_likers
+    }
+
     /// Sorts the posts with a comparison function, **without** preserving the initial order of
     /// equal elements.
     ///
@@ -167,29 +242,28 @@ impl Posts {
     /// Performs an inplace, unstable sort of the post episode number in an descending order.
     pub fn sort_by_episode_desc(&mut self) {
         self.posts
-            .sort_unstable_by(|a, b| b.episode.number.cmp(&a.episode.number));
+            .sort_unstable_by_key(|post| Reverse(post.episode.number));
     }
 
     /// Performs an inplace, unstable sort of the post episode number in an ascending order.
     pub fn sort_by_episode_asc(&mut self) {
-        self.posts
-            .sort_unstable_by(|a, b| a.episode.number.cmp(&b.episode.number));
+        self.posts.sort_unstable_by_key(|post| post.episode.number);
     }
 
     /// Performs an inplace, unstable sort of the post date, from newest to oldest.
     pub fn sort_by_newest(&mut self) {
-        self.posts.sort_unstable_by(|a, b| b.posted.cmp(&a.posted));
+        self.posts.sort_unstable_by_key(|post| Reverse(post.posted));
     }
 
     /// Performs an inplace, unstable sort of the post date, from oldest to newest.
     pub fn sort_by_oldest(&mut self) {
-        self.posts.sort_unstable_by(|a, b| a.posted.cmp(&b.posted));
+        self.posts.sort_unstable_by_key(|post| post.posted);
     }
 
     /// Performs an inplace, unstable sort of the upvotes , from largest to smallest.
     pub fn sort_by_upvotes(&mut self) {
         self.posts
-            .sort_unstable_by(|a, b| b.upvotes.cmp(&a.upvotes));
+            .sort_unstable_by_key(|post| Reverse(post.upvotes));
     }
 
     /// Return the underlying `Vec<Post>` as a slice.
@@ -197,11 +271,107 @@ impl Posts {
     pub fn as_slice(&self) -> &[Post] {
         &self.posts
     }
+
+    /// Writes the posts as CSV to `writer`, one row per post after a header row.
+    ///
+    /// The columns are `episode`, `id`, `parent_id`, `username`, `body`, `upvotes`, `downvotes`, `is_spoiler`,
+    /// `is_deleted`, and `posted`. `parent_id` is empty for top-level comments, and `posted` is in RFC 3339 format.
+    ///
+    /// Fields containing commas, quotes, or newlines are quoted, with inner quotes doubled.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// let posts = webtoon.posts().await?;
+    /// let file = std::fs::File::create("posts.csv")?;
+    /// posts.write_csv(file)?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    #[cfg(feature = "csv")]
+    pub fn write_csv(&self, writer: impl std::io::Write) -> std::io::Result<()> {
+        let mut csv = csv::Writer::from_writer(writer);
+
+        csv.write_record([
+            "episode",
+            "id",
+            "parent_id",
+            "username",
+            "body",
+            "upvotes",
+            "downvotes",
+            "is_spoiler",
+            "is_deleted",
+            "posted",
+        ])?;
+
+        for post in &self.posts {
+            let parent_id = if post.is_reply() {
+                post.parent_id.to_string()
+            } else {
+                String::new()
+            };
+
+            csv.write_record([
+                post.episode().to_string(),
+                post.id.to_string(),
+                parent_id,
+                post.poster.username().to_string(),
+                post.body.contents().to_string(),
+                post.upvotes.to_string(),
+                post.downvotes.to_string(),
+                post.body.is_spoiler().to_string(),
+                post.is_deleted.to_string(),
+                post.posted.to_rfc3339(),
+            ])?;
+        }
+
+        csv.flush()?;
+
+        Ok(())
+    }
 }
 
 // Replies for post
 //GET https://www.webtoons.com/p/api/community/v2/post/GW-epicom:0-c_843910_1-k/child-posts?sort=oldest&displayBlindCommentAsService=false&prevSize=0&nextSize=10&withCursor=false&offsetPostId=
 
+/// A top-level comment together with all of its replies, as returned by [`Post::thread`].
+#[derive(Debug, Clone)]
+pub struct Thread {
+    root: Post,
+    replies: Vec<Post>,
+}
+
+impl Thread {
+    /// Returns the top-level comment the thread starts from.
+    #[must_use]
+    pub fn root(&self) -> &Post {
+        &self.root
+    }
+
+    /// Returns the replies to the root comment, from oldest to newest.
+    #[must_use]
+    pub fn replies(&self) -> &[Post] {
+        &self.replies
+    }
+
+    /// Splits the thread into its root comment and replies.
+    #[must_use]
+    pub fn into_parts(self) -> (Post, Vec<Post>) {
+        (self.root, self.replies)
+    }
+}
+
 /// Represensts a post on `webtoons.com`, either a reply or a top-level comment.
 #[derive(Clone)]
 pub struct Post {
@@ -215,6 +385,7 @@ pub struct Post {
     pub(crate) is_top: bool,
     pub(crate) is_deleted: bool,
     pub(crate) posted: DateTime<Utc>,
+    pub(crate) updated: DateTime<Utc>,
     pub(crate) poster: Poster,
 }
 
@@ -232,6 +403,7 @@ impl fmt::Debug for Post {
             .field("is_top", &self.is_top)
             .field("is_deleted", &self.is_deleted)
             .field("posted", &self.posted)
+            .field("updated", &self.updated)
             .field("poster", &self.poster)
             .finish()
     }
@@ -307,6 +479,60 @@ impl Post {
         self.parent_id
     }
 
+    /// Fetches the top-level comment that this reply was left on.
+    ///
+    /// This is useful when reconstructing threads from a flat list of replies, without having to build a map of ids to
+    /// comments.
+    ///
+    /// ### Returns
+    ///
+    /// - `Ok(Some(Post))`: The parent comment. This can still be a deleted comment, see [`Post::is_deleted`].
+    /// - `Ok(None)`: If this post is itself a top-level comment, or the parent no longer exists.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{ Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # let posts = webtoon.posts().await?;
+    /// # if let Some(post) = posts.into_iter().next() {
+    /// # let replies: webtoon::platform::webtoons::webtoon::episode::posts::Posts = post.replies().await?;
+    /// for reply in replies {
+    ///     if let Some(parent) = reply.parent().await? {
+    ///         println!("{} replied to {}", reply.poster().username(), parent.poster().username());
+    ///     }
+    /// }
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `PostError::ClientError`: If there was an issue with the request.
+    /// - `PostError::Unexpected`: If the response could not be parsed.
+    pub async fn parent(&self) -> Result<Option<Post>, PostError> {
+        if self.is_comment() {
+            return Ok(None);
+        }
+
+        let response = self.episode.webtoon.client.get_post(self.parent_id).await?;
+
+        if response.status() == 404 {
+            return Ok(None);
+        }
+
+        let response = response.text().await?;
+
+        let api = serde_json::from_str::<PostResult>(&response).context(response)?;
+
+        Ok(Some(Post::try_from((&self.episode, api.result))?))
+    }
+
     /// Returns a reference to the [`Body`] of the post.
     ///
     /// This method provides access to the content of the post and whether it contains spoilers.
@@ -388,12 +614,29 @@ impl Post {
         self.episode.number()
     }
 
-    /// Returns the posts' published date in an ISO 8601 millisecond timestamp format.
+    /// Returns the posts' published date as a unix timestamp in milliseconds.
     #[must_use]
     pub fn posted(&self) -> i64 {
         self.posted.timestamp_millis()
     }
 
+    /// Returns whether the post was edited after it was posted.
+    ///
+    /// The API only exposes when the post was last updated, not what was changed, so this is `true` whenever that
+    /// differs from when it was posted. Deleted posts always return `false`, as deleting also counts as an update.
+    #[must_use]
+    pub fn is_edited(&self) -> bool {
+        !self.is_deleted && self.updated > self.posted
+    }
+
+    /// Returns when the post was last edited, as a unix timestamp in milliseconds like [`Post::posted`].
+    ///
+    /// Returns `None` if [`Post::is_edited`] is `false`.
+    #[must_use]
+    pub fn edited_at(&self) -> Option<i64> {
+        self.is_edited().then(|| self.updated.timestamp_millis())
+    }
+
     /// Upvotes post via users session.
     ///
     /// # Returns
@@ -519,9 +762,12 @@ impl Post {
             .delete(url)
             .header("Service-Ticket-Id", "epicom")
             .header("Referer", "https://www.webtoons.com/")
-            .header("Cookie", format!("NEO_SES={session}"))
+            .header(
+                "Cookie",
+                self.episode.webtoon.client.session_cookie(&session),
+            )
             .header("Api-Token", token)
-            .send()
+            .send_traced()
             .await?;
 
         let mut reaction = self.poster.reaction.write().await;
@@ -605,6 +851,50 @@ impl Post {
         R::replies(self).await
     }
 
+    /// Returns the whole discussion this post is part of: the top-level comment with all of its replies.
+    ///
+    /// When called on a reply, the thread of its parent comment is returned. If that parent no longer exists, the
+    /// reply itself is used as the root.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{ Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// # let posts = webtoon.posts().await?;
+    /// # if let Some(post) = posts.into_iter().next() {
+    /// let thread = post.thread().await?;
+    ///
+    /// println!("{}", thread.root().body().contents());
+    /// for reply in thread.replies() {
+    ///     println!("  {}", reply.body().contents());
+    /// }
+    /// # }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// Returns a [`PostError`] if fetching the parent or the replies fails.
+    pub async fn thread(&self) -> Result<Thread, PostError> {
+        let root = if self.is_reply() {
+            self.parent().await?.unwrap_or_else(|| self.clone())
+        } else {
+            self.clone()
+        };
+
+        let replies: Posts = root.replies().await?;
+        let mut replies = replies.posts;
+        replies.sort_by_key(|reply| reply.id);
+
+        Ok(Thread { root, replies })
+    }
+
     /// Posts a reply on top-level comment.
     ///
     /// This method allows users to leave a reply on a top-level comment. The reply can be marked as a spoiler.
@@ -641,8 +931,11 @@ impl Post {
         self.episode
             .webtoon
             .client
-            .post_reply(self, body, is_spoiler)
+            .post_reply(&self.episode, self.id, body, is_spoiler)
             .await?;
+
+        self.episode.forget_comments_and_replies().await;
+
         Ok(())
     }
 
@@ -680,6 +973,8 @@ impl Post {
 
         self.episode.webtoon.client.delete_post(self).await?;
 
+        self.episode.forget_comments_and_replies().await;
+
         Ok(())
     }
 }
@@ -733,6 +1028,15 @@ impl TryFrom<(&Episode, webtoons::client::posts::Post)> for Post {
         let is_deleted = post.status == "DELETE";
         let is_spoiler = post.settings.spoiler_filter == "ON";
 
+        let super_like = post
+            .section_group
+            .sections
+            .iter()
+            .find_map(|section| match section {
+                Section::SuperLike { data, .. } => Some(data.super_like_count),
+                _ => None,
+            });
+
         // Only Webtoon flare can have multiple.
         // Super likes might be able to exist along with other flare?
         let flare = if post.section_group.sections.len() > 1 {
@@ -799,6 +1103,12 @@ impl TryFrom<(&Episode, webtoons::client::posts::Post)> for Post {
                     post.created_at
                 )
             })?,
+            updated: DateTime::from_timestamp_millis(post.updated_at).with_context(|| {
+                format!(
+                    "`{}` is not a valid unix millisecond timestamp",
+                    post.updated_at
+                )
+            })?,
             poster: Poster {
                 webtoon: episode.webtoon.clone(),
                 episode: episode.number,
@@ -806,11 +1116,18 @@ impl TryFrom<(&Episode, webtoons::client::posts::Post)> for Post {
                 cuid: Arc::from(post.created_by.cuid),
                 profile: Arc::from(post.created_by.profile_url),
                 username: Arc::from(post.created_by.name),
+                avatar: post
+                    .created_by
+                    .profile_image
+                    .url
+                    .filter(|url| !url.is_empty())
+                    .map(Arc::from),
                 is_current_session_user: post.created_by.is_page_owner,
                 is_current_webtoon_creator: post.created_by.is_page_owner,
                 is_creator: post.created_by.is_creator,
                 is_blocked: post.created_by.restriction.is_write_post_restricted,
                 reaction: Arc::new(RwLock::new(reaction)),
+                super_like,
             },
         })
     }
@@ -853,11 +1170,81 @@ pub enum Flare {
     /// A GIF in a post.
     Giphy(Giphy),
     /// A list of webtoons in a post.
+    ///
+    /// The handles are made from the links in the post without any requests, the same as
+    /// [`Client::webtoon_from_url`](crate::platform::webtoons::Client::webtoon_from_url). Nothing is fetched until a
+    /// method like [`Webtoon::title`] is called, so parsing posts with many linked webtoons costs no extra requests.
     Webtoons(Vec<Webtoon>),
     /// A sticker in a post.
     Sticker(Sticker),
 }
 
+impl Flare {
+    /// Returns the [`FlareKind`] of the flare, for cheaply branching on the kind without matching on the data.
+    #[must_use]
+    pub fn kind(&self) -> FlareKind {
+        match self {
+            Self::Giphy(_) => FlareKind::Giphy,
+            Self::Webtoons(_) => FlareKind::Webtoons,
+            Self::Sticker(_) => FlareKind::Sticker,
+        }
+    }
+}
+
+/// Serializes only the ids needed to identify the flare, so it can be stored without the live [`Webtoon`] handles.
+///
+/// - `Giphy`: `{"kind":"giphy","id":"..."}`
+/// - `Sticker`: `{"kind":"sticker","id":"wt_001-v2-1"}`
+/// - `Webtoons`: `{"kind":"webtoons","webtoons":[{"id":95,"type":"Original"}]}`
+impl Serialize for Flare {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct WebtoonId {
+            id: u32,
+            r#type: Type,
+        }
+
+        #[derive(Serialize)]
+        #[serde(tag = "kind", rename_all = "snake_case")]
+        enum Repr<'a> {
+            Giphy { id: &'a str },
+            Webtoons { webtoons: Vec<WebtoonId> },
+            Sticker { id: String },
+        }
+
+        let repr = match self {
+            Self::Giphy(giphy) => Repr::Giphy { id: giphy.id() },
+            Self::Webtoons(webtoons) => Repr::Webtoons {
+                webtoons: webtoons
+                    .iter()
+                    .map(|webtoon| WebtoonId {
+                        id: webtoon.id(),
+                        r#type: webtoon.r#type(),
+                    })
+                    .collect(),
+            },
+            Self::Sticker(sticker) => Repr::Sticker { id: sticker.id() },
+        };
+
+        repr.serialize(serializer)
+    }
+}
+
+/// The kind of a [`Flare`], without its data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FlareKind {
+    /// A GIF in a post.
+    Giphy,
+    /// A list of webtoons in a post.
+    Webtoons,
+    /// A sticker in a post.
+    Sticker,
+}
+
 /// Represents a sticker in a post.
 #[derive(Debug, Clone)]
 pub struct Sticker {
@@ -1049,6 +1436,9 @@ impl Giphy {
 // }
 
 /// Represents information about the poster of a [`Post`].
+///
+/// Two posters are equal, and hash the same, when they are the same account, so posters can be used to group posts
+/// by who left them.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone)]
 pub struct Poster {
@@ -1058,11 +1448,13 @@ pub struct Poster {
     pub(crate) cuid: Arc<str>,
     pub(crate) profile: Arc<str>,
     pub(crate) username: Arc<str>,
+    pub(crate) avatar: Option<Arc<str>>,
     pub(crate) is_creator: bool,
     pub(crate) is_blocked: bool,
     pub(crate) is_current_session_user: bool,
     pub(crate) is_current_webtoon_creator: bool,
     pub(crate) reaction: Arc<RwLock<Reaction>>,
+    pub(crate) super_like: Option<u32>,
 }
 
 #[expect(clippy::missing_fields_in_debug)]
@@ -1074,6 +1466,7 @@ impl fmt::Debug for Poster {
             .field("cuid", &self.cuid)
             .field("profile", &self.profile)
             .field("username", &self.username)
+            .field("avatar", &self.avatar)
             .field("is_creator", &self.is_creator)
             .field("is_blocked", &self.is_blocked)
             .field("is_current_session_user", &self.is_current_session_user)
@@ -1082,6 +1475,7 @@ impl fmt::Debug for Poster {
                 &self.is_current_webtoon_creator,
             )
             .field("reaction", &self.reaction)
+            .field("super_like", &self.super_like)
             .finish()
     }
 }
@@ -1095,18 +1489,56 @@ impl Poster {
         &self.cuid
     }
 
+    /// Returns whether both posters are the same account, comparing their [`Poster::cuid`].
+    ///
+    /// This is the same as `==`, and holds across episodes and webtoons.
+    #[must_use]
+    pub fn same_account_as(&self, other: &Poster) -> bool {
+        self.cuid == other.cuid
+    }
+
     /// Returns the profile segment for poster in `webtoons.com/*/creator/{profile}`.
     #[must_use]
     pub fn profile(&self) -> &str {
         &self.profile
     }
 
+    /// Returns the full url to the poster's profile page for the given language version of the site.
+    ///
+    /// Returns `None` if the poster has no profile, as is the case for some older accounts.
+    #[must_use]
+    pub fn profile_url(&self, language: Language) -> Option<String> {
+        if self.profile.is_empty() {
+            return None;
+        }
+
+        Some(format!(
+            "https://www.webtoons.com/{}/creator/{}",
+            language.as_str(),
+            self.profile
+        ))
+    }
+
+    /// Returns the url of the poster's avatar image, if they have set one.
+    #[must_use]
+    pub fn avatar(&self) -> Option<&str> {
+        self.avatar.as_deref()
+    }
+
     /// Returns poster username.
     #[must_use]
     pub fn username(&self) -> &str {
         &self.username
     }
 
+    /// Returns the amount of super likes the poster gave with the post, if any.
+    ///
+    /// Super likes are sent along with a comment, so this is `None` for posts that were left without one.
+    #[must_use]
+    pub fn super_like(&self) -> Option<u32> {
+        self.super_like
+    }
+
     /// Returns if the session user reacted to post.
     ///
     /// Returns `true` if the user reacted, `false` if not.
@@ -1188,8 +1620,7 @@ impl Poster {
         let session = self
             .webtoon
             .client
-            .session
-            .as_ref()
+            .session()
             .ok_or(ClientError::NoSessionProvided)?;
 
         self.webtoon
@@ -1198,10 +1629,10 @@ impl Poster {
             .post(url)
             .header("Service-Ticket-Id", "epicom")
             .header("Referer", "https://www.webtoons.com/")
-            .header("Cookie", format!("NEO_SES={session}"))
+            .header("Cookie", self.webtoon.client.session_cookie(&session))
             .header("Api-Token", token)
             .json(&payload)
-            .send()
+            .send_traced()
             .await?;
 
         Ok(())
@@ -1222,6 +1653,20 @@ impl PartialEq for Post {
 
 impl Eq for Post {}
 
+impl Hash for Poster {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.cuid.hash(state);
+    }
+}
+
+impl PartialEq for Poster {
+    fn eq(&self, other: &Self) -> bool {
+        self.same_account_as(other)
+    }
+}
+
+impl Eq for Poster {}
+
 /// Represents a reaction for a post.
 ///
 /// These are mutually exclusive.
@@ -1259,6 +1704,32 @@ impl IntoIterator for Posts {
     }
 }
 
+/// Order in which the server returns an episode's posts.
+///
+/// Used with [`Episode::posts_sorted`](super::Episode::posts_sorted).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PostSort {
+    /// Most recently posted first. This is what the site shows by default.
+    #[default]
+    Newest,
+    /// Earliest posted first.
+    Oldest,
+    /// The platform's own ranking of the posts, as shown under the "Top" tab.
+    Best,
+}
+
+impl PostSort {
+    /// The value the community API expects for its `sort` query parameter.
+    pub(crate) const fn as_param(self) -> &'static str {
+        match self {
+            Self::Newest => "NEWEST",
+            Self::Oldest => "OLDEST",
+            Self::Best => "FAVORITE",
+        }
+    }
+}
+
 impl From<Vec<Post>> for Posts {
     fn from(value: Vec<Post>) -> Self {
         Self { posts: value }
@@ -1343,3 +1814,128 @@ impl Replies for Posts {
         Ok(replies)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_serialize_flare_ids() {
+        let sticker = Flare::Sticker(Sticker::from_str("wt_001-v2-1").unwrap());
+        let giphy = Flare::Giphy(Giphy::new("abc".to_string()));
+        let webtoons = Flare::Webtoons(vec![Webtoon::from_url_with_client(
+            "https://www.webtoons.com/en/fantasy/tower-of-god/list?title_no=95",
+            &webtoons::Client::new(),
+        )
+        .unwrap()]);
+
+        assert_eq!(sticker.kind(), FlareKind::Sticker);
+        assert_eq!(
+            serde_json::to_string(&sticker).unwrap(),
+            r#"{"kind":"sticker","id":"wt_001-v2-1"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&giphy).unwrap(),
+            r#"{"kind":"giphy","id":"abc"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&webtoons).unwrap(),
+            r#"{"kind":"webtoons","webtoons":[{"id":95,"type":"Original"}]}"#
+        );
+    }
+
+    /// A post on `episode` of the testing webtoon, by `username`, with everything else left empty.
+    fn post_fixture(episode: u16, post: &str, username: &str, super_like: Option<u32>) -> Post {
+        let webtoon = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/en/canvas/testing-service/list?title_no=843910",
+            &webtoons::Client::new(),
+        )
+        .unwrap();
+
+        let id = Id::from_str(&format!("GW-epicom:0-c_843910_{episode}-{post}")).unwrap();
+        let posted = DateTime::from_timestamp_millis(1_700_000_000_000).unwrap();
+
+        Post {
+            episode: Episode::new(&webtoon, episode),
+            id,
+            parent_id: id,
+            body: Body {
+                contents: Arc::from(""),
+                is_spoiler: false,
+                flare: None,
+            },
+            upvotes: 0,
+            downvotes: 0,
+            replies: 0,
+            is_top: false,
+            is_deleted: false,
+            posted,
+            updated: posted,
+            poster: Poster {
+                webtoon,
+                episode,
+                post_id: id,
+                cuid: Arc::from(username),
+                profile: Arc::from(""),
+                username: Arc::from(username),
+                avatar: None,
+                is_creator: false,
+                is_blocked: false,
+                is_current_session_user: false,
+                is_current_webtoon_creator: false,
+                reaction: Arc::new(RwLock::new(Reaction::None)),
+                super_like,
+            },
+        }
+    }
+
+    #[test]
+    fn should_sum_super_likes_per_poster() {
+        let posts = Posts::from(vec![
+            post_fixture(1, "1", "b", Some(5)),
+            post_fixture(1, "2", "a", Some(3)),
+            post_fixture(2, "1", "a", Some(2)),
+            post_fixture(2, "2", "c", None),
+            post_fixture(3, "1", "d", Some(0)),
+        ]);
+
+        let totals: Vec<(String, u32)> = posts
+            .super_likers()
+            .into_iter()
+            .map(|(poster, total)| (poster.username().to_string(), total))
+            .collect();
+
+        assert_eq!(vec![("a".to_string(), 5), ("b".to_string(), 5)], totals);
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn should_round_trip_csv_with_quotes_and_newlines() {
+        let contents = "She said \"hi, there\"\nand left";
+
+        let mut post = post_fixture(1, "1d", "a, b", None);
+        post.body = Body {
+            contents: Arc::from(contents),
+            is_spoiler: true,
+            flare: None,
+        };
+        post.upvotes = 3;
+        post.downvotes = 1;
+
+        let mut buffer = Vec::new();
+        Posts { posts: vec![post] }.write_csv(&mut buffer).unwrap();
+
+        let mut reader = csv::Reader::from_reader(buffer.as_slice());
+        let records = reader.records().collect::<Result<Vec<_>, _>>().unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(&records[0][0], "1");
+        assert_eq!(&records[0][1], "GW-epicom:0-c_843910_1-1d");
+        assert_eq!(&records[0][2], "");
+        assert_eq!(&records[0][3], "a, b");
+        assert_eq!(&records[0][4], contents);
+        assert_eq!(&records[0][7], "true");
+        assert_eq!(&records[0][9], "2023-11-14T22:13:20+00:00");
+    }
+}
@@ -3,9 +3,12 @@
 mod dashboard;
 pub mod episode;
 mod page;
+pub mod snapshot;
 
 use anyhow::Context;
 use core::fmt;
+use std::collections::BTreeMap;
+use std::hash::Hash;
 use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -16,10 +19,16 @@ pub mod rss;
 use rss::Rss;
 
 use self::{
-    episode::{posts::Posts, Episode, Episodes},
+    episode::{
+        posts::{Poster, Posts},
+        Episode, Episodes,
+    },
     page::Page,
+    snapshot::StatsSnapshot,
 };
 
+use super::client::likes::Likes;
+use super::client::trace::Traced;
 use super::errors::{ClientError, EpisodeError, PostError, WebtoonError};
 use super::meta::{Genre, Scope};
 use super::originals::Release;
@@ -42,6 +51,8 @@ pub struct Webtoon {
     pub(super) slug: Arc<str>,
     /// Cache for data on the Wetboons landing page: title, rating, etc.
     pub(super) page: Arc<Mutex<Option<Page>>>,
+    /// Genres known without scraping the landing page, like from the titles API.
+    pub(super) genres: Option<Arc<[Genre]>>,
 }
 
 #[expect(clippy::missing_fields_in_debug)]
@@ -58,6 +69,27 @@ impl fmt::Debug for Webtoon {
     }
 }
 
+/// Two `Webtoon` handles are equal when they point to the same webtoon: the same `language`, [`Type`], and `id`.
+///
+/// Canvas and Original ids are separate, so a canvas and an original webtoon can share an id and still differ. The
+/// slug, genre, and any cached page data are not compared, so a handle from [`Client::originals`] and one from
+/// [`Creator::webtoons`] for the same series are considered equal.
+impl PartialEq for Webtoon {
+    fn eq(&self, other: &Self) -> bool {
+        self.language == other.language && self.r#type() == other.r#type() && self.id == other.id
+    }
+}
+
+impl Eq for Webtoon {}
+
+impl Hash for Webtoon {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.language.hash(state);
+        self.r#type().hash(state);
+        self.id.hash(state);
+    }
+}
+
 impl Webtoon {
     /// Returns the language of this `Webtoon`.
     pub fn language(&self) -> Language {
@@ -77,6 +109,56 @@ impl Webtoon {
         }
     }
 
+    /// Confirms with webtoons.com that this `Webtoon` really is of the [`Type`] it was constructed as.
+    ///
+    /// A `Webtoon` made from a URL, like with [`Client::webtoon_from_url`], trusts the URL's segments. An originals path
+    /// with the `title_no` of a Canvas series would otherwise only show up later as a confusing failure in some
+    /// unrelated method.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{errors::Error, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// let webtoon = client
+    ///     .webtoon_from_url("https://www.webtoons.com/en/action/omniscient-reader/list?title_no=2154")?;
+    ///
+    /// webtoon.verify().await?;
+    /// # Ok(())}
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `WebtoonError::ScopeMismatch`: If webtoons.com has no webtoon of the constructed type with this id. `found`
+    ///   holds the type it does exist as, if any.
+    /// - `WebtoonError::ClientError`: If there is an issue with the request.
+    pub async fn verify(&self) -> Result<(), WebtoonError> {
+        let expected = self.r#type();
+
+        if self
+            .client
+            .get_webtoon_type(self.language, self.id, expected)
+            .await?
+            == Some(expected)
+        {
+            return Ok(());
+        }
+
+        let other = match expected {
+            Type::Original => Type::Canvas,
+            Type::Canvas => Type::Original,
+        };
+
+        let found = self
+            .client
+            .get_webtoon_type(self.language, self.id, other)
+            .await?;
+
+        Err(WebtoonError::ScopeMismatch { expected, found })
+    }
+
     /// Returns the title of this `Webtoon`.
     pub async fn title(&self) -> Result<String, WebtoonError> {
         let mut guard = self.page.lock().await;
@@ -95,7 +177,31 @@ impl Webtoon {
         }
     }
 
+    /// Returns the original, untranslated, title of this `Webtoon`, if the page's metadata has it.
+    ///
+    /// This is mostly useful for matching the same series across the different language sites. Returns `None` when
+    /// the page doesn't expose it, or when it's the same as [`Webtoon::title`].
+    pub async fn original_title(&self) -> Result<Option<String>, WebtoonError> {
+        let mut guard = self.page.lock().await;
+
+        if let Some(page) = &*guard {
+            Ok(page.original_title().map(str::to_owned))
+        } else {
+            let page = page::scrape(self).await?;
+
+            let original_title = page.original_title().map(str::to_owned);
+
+            *guard = Some(page);
+            drop(guard);
+
+            Ok(original_title)
+        }
+    }
+
     /// Returns a list of [`Creator`] for this `Webtoon`.
+    ///
+    /// Each creator appears once, even when credited for both the story and the art, and they are in the order the
+    /// page credits them: the writer first, then the artist, then anyone else.
     pub async fn creators(&self) -> Result<Vec<Creator>, WebtoonError> {
         let mut guard = self.page.lock().await;
 
@@ -116,11 +222,21 @@ impl Webtoon {
     /// Returns a list of [`Genre`] for this `Webtoon`.
     ///
     /// For Originals, the genres are suplumented from the `/genres` page, so you may see more genres than you initially expect.
+    /// Canvas webtoons have no genre in their URL, so theirs come from the page alone.
+    ///
+    /// Each genre appears once. The primary genre, the first one the page shows, always comes first, followed by the
+    /// rest in alphabetical order of their slug, so the list is stable across runs. The genre from the URL the
+    /// `Webtoon` was made from is always included, even when the page itself doesn't list it.
+    ///
+    /// Webtoons from [`Creator::webtoons`](super::creator::Creator::webtoons) already have their genres from the
+    /// titles API, so no request is made for them until the landing page is needed for something else.
     pub async fn genres(&self) -> Result<Vec<Genre>, WebtoonError> {
         let mut guard = self.page.lock().await;
 
         if let Some(page) = &*guard {
             Ok(page.genres().to_vec())
+        } else if let Some(genres) = &self.genres {
+            Ok(genres.to_vec())
         } else {
             let page = page::scrape(self).await?;
 
@@ -134,6 +250,8 @@ impl Webtoon {
     }
 
     /// Returns the summary for this `Webtoon`.
+    ///
+    /// HTML entities are decoded, and line breaks the creator put in the summary are kept as `\n`.
     pub async fn summary(&self) -> Result<String, WebtoonError> {
         let mut guard = self.page.lock().await;
 
@@ -200,7 +318,7 @@ impl Webtoon {
     pub async fn views(&self) -> Result<u64, EpisodeError> {
         match self.client.get_user_info_for_webtoon(self).await {
             // TODO: Only English dashboards are supported for now.
-            Ok(user) if user.is_webtoon_creator() && self.language == Language::En => {
+            Ok(user) if user.is_webtoon_creator() && self.language.supports_dashboards() => {
                 let views = dashboard::episodes::scrape(self)
                     .await?
                     .into_iter()
@@ -233,6 +351,60 @@ impl Webtoon {
         }
     }
 
+    /// Returns the total views of this `Webtoon`, summed from the view counts shown on each episode page.
+    ///
+    /// Without a creator session, [`Webtoon::views`] is the rounded number from the landing page, like `1.2M`. Some
+    /// language sites show an exact count on each episode page instead, and adding those up gives a more precise
+    /// total. With a creator session the exact views from the dashboard are used, as with `views`.
+    ///
+    /// This is much slower than `views`, as each episode page is requested, one after the other.
+    ///
+    /// ### Returns
+    ///
+    /// - `Ok(Some(u64))`: The sum of every episode's views.
+    /// - `Ok(None)`: If any episode page doesn't show an exact view count, which is the case for most languages.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(95, Type::Original).await? {
+    /// match webtoon.views_from_episodes().await? {
+    ///     Some(views) => println!("{views} views"),
+    ///     None => println!("about {} views", webtoon.views().await?),
+    /// }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `EpisodeError::ClientError`: If there was an issue with a request.
+    /// - `EpisodeError::Unexpected`: If the episode list or an episode page couldn't be scraped.
+    pub async fn views_from_episodes(&self) -> Result<Option<u64>, EpisodeError> {
+        let episodes = self.episodes().await?;
+
+        if let Some(views) = episodes.total_views() {
+            return Ok(Some(views));
+        }
+
+        let mut total = 0;
+
+        for episode in episodes {
+            let Some(views) = episode.public_views().await? else {
+                return Ok(None);
+            };
+
+            total += u64::from(views);
+        }
+
+        Ok(Some(total))
+    }
+
     /// Retrieves the total number of subscribers for this `Webtoon`.
     ///
     /// The method determines the subscriber count based on whether the current session belongs to the
@@ -283,15 +455,8 @@ impl Webtoon {
     /// - The accuracy of the subscriber count depends on whether the session matches a webtoon creator. If it does, detailed statistics
     ///   from the creator's dashboard are used for more precision. Otherwise, the subscriber count is retrieved from the webtoon's main page.
     pub async fn subscribers(&self) -> Result<u32, WebtoonError> {
-        match self.client.get_user_info_for_webtoon(self).await {
-            // TODO: Only english dashboards supported for now
-            Ok(user) if user.is_webtoon_creator() && self.language == Language::En => {
-                let subscribers = dashboard::stats::scrape(self).await?.subscribers;
-                return Ok(subscribers);
-            }
-            // Fallback to public data
-            Ok(_) | Err(ClientError::NoSessionProvided) => {}
-            Err(err) => return Err(WebtoonError::ClientError(err)),
+        if let Some(subscribers) = self.subscribers_exact().await? {
+            return Ok(subscribers);
         }
 
         let mut guard = self.page.lock().await;
@@ -310,6 +475,50 @@ impl Webtoon {
         }
     }
 
+    /// Returns the exact subscriber count from the creator's stats dashboard, without falling back to the public page.
+    ///
+    /// [`Webtoon::subscribers`] quietly uses the rounded public number whenever the dashboard can't be used, so this is
+    /// the way to tell whether the precise number was really gotten.
+    ///
+    /// ### Returns
+    ///
+    /// - `Ok(Some(u32))`: The subscriber count from the dashboard.
+    /// - `Ok(None)`: If there is no session, the session isn't one of this webtoon's creators, or the dashboard isn't
+    ///   available in the webtoon's language.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{ Client, Language, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::with_session("session");
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// match webtoon.subscribers_exact().await? {
+    ///     Some(subscribers) => println!("Exactly {subscribers} subscribers"),
+    ///     None => println!("Not a creator of this webtoon"),
+    /// }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `WebtoonError::ClientError`: If the user info or dashboard request fails.
+    /// - `WebtoonError::Unexpected`: If the dashboard was reached but couldn't be scraped, like after a markup change.
+    pub async fn subscribers_exact(&self) -> Result<Option<u32>, WebtoonError> {
+        match self.client.get_user_info_for_webtoon(self).await {
+            // TODO: Only english dashboards supported for now
+            Ok(user) if user.is_webtoon_creator() && self.language.supports_dashboards() => {
+                let subscribers = dashboard::stats::scrape(self).await?.subscribers;
+                Ok(Some(subscribers))
+            }
+            Ok(_) | Err(ClientError::NoSessionProvided) => Ok(None),
+            Err(err) => Err(WebtoonError::ClientError(err)),
+        }
+    }
+
     /// Returns the rating for this `Webtoon`.
     pub async fn rating(&self) -> Result<f64, WebtoonError> {
         let mut guard = self.page.lock().await;
@@ -479,7 +688,8 @@ impl Webtoon {
     ///
     /// - **For Regular Users**: If the session is not provided or the user is not the creator of the webtoon, the method will scrape the publicly available episodes:
     ///   - Only episodes that are publicly visible on the webtoon's main page will be retrieved.
-    ///   - Episodes behind fast-pass or ad walls will not be included.
+    ///   - Episodes behind fast-pass or ad walls will not be included, unless a session is provided and its user has
+    ///     unlocked them. These come after the public episodes, and `Episode::published()` returns `None` for them.
     ///   - View counts (`Episode::views()`) will return `None` for episodes retrieved from the main page as the information is unavailable.
     ///   - The publication time (`Episode::published()`) will return `Some(i64)` but the time will always be set to `2:00 AM` on the episode's published date.
     ///
@@ -511,8 +721,6 @@ impl Webtoon {
     ///
     ///     if let Some(published) = episode.published() {
     ///         println!("Published at: {}", published);
-    ///     } else {
-    ///         panic!("Publish date should always be available with `episodes`.");
     ///     }
     /// }
     /// # }
@@ -525,20 +733,73 @@ impl Webtoon {
     /// - `EpisodeError::ClientError`: If there is an issue with the client during the retrieval process.
     /// - `EpisodeError::Unexpected`: If an unexpected error occurs during the scraping of episode data.
     pub async fn episodes(&self) -> Result<Episodes, EpisodeError> {
+        self.episodes_after(None).await
+    }
+
+    /// Retrieves only the episodes with a number greater than `last_known`, for incrementally syncing a webtoon.
+    ///
+    /// This follows the same rules as [`Webtoon::episodes`], and the returned episodes carry the same data.
+    ///
+    /// ### Behavior
+    ///
+    /// - **Public Data**: The episode list goes from newest to oldest, so pages stop being requested once an episode at
+    ///   or before `last_known` shows up. Polling for new episodes usually takes a single page.
+    /// - **Creator Dashboard**: The dashboard is still read in full, and the episodes are filtered afterwards.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{ Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// let last_known = 120;
+    ///
+    /// for episode in webtoon.episodes_since(last_known).await? {
+    ///     println!("New episode: {}", episode.number());
+    /// }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `EpisodeError::ClientError`: If there is an issue with the client during the retrieval process.
+    /// - `EpisodeError::Unexpected`: If an unexpected error occurs during the scraping of episode data.
+    pub async fn episodes_since(&self, last_known: u16) -> Result<Episodes, EpisodeError> {
+        self.episodes_after(Some(last_known)).await
+    }
+
+    async fn episodes_after(&self, since: Option<u16>) -> Result<Episodes, EpisodeError> {
         let episodes = match self.client.get_user_info_for_webtoon(self).await {
             // TODO: Only English dashboards are supported for now.
-            Ok(user) if user.is_webtoon_creator() && self.language == Language::En => {
-                self::dashboard::episodes::scrape(self).await?
+            Ok(user) if user.is_webtoon_creator() && self.language.supports_dashboards() => {
+                let mut episodes = self::dashboard::episodes::scrape(self).await?;
+
+                if let Some(since) = since {
+                    episodes.retain(|episode| episode.number() > since);
+                }
+
+                episodes
             }
-            // Fallback to public data
-            Ok(_) | Err(ClientError::NoSessionProvided) => {
-                page::episodes(self).await.map_err(|err| match err {
-                    WebtoonError::ClientError(client_error) => {
-                        EpisodeError::ClientError(client_error)
-                    }
-                    error => EpisodeError::Unexpected(error.into()),
-                })?
+            // Fallback to public data, along with what a reader has unlocked.
+            Ok(_) => {
+                let mut episodes = self.public_episodes(since).await?;
+
+                let latest = episodes
+                    .iter()
+                    .map(Episode::number)
+                    .max()
+                    .or(since)
+                    .unwrap_or_default();
+
+                episodes.extend(self.unlocked_episodes(latest).await?);
+
+                episodes
             }
+            Err(ClientError::NoSessionProvided) => self.public_episodes(since).await?,
             Err(err) => return Err(EpisodeError::ClientError(err)),
         };
 
@@ -549,6 +810,38 @@ impl Webtoon {
         })
     }
 
+    async fn public_episodes(&self, since: Option<u16>) -> Result<Vec<Episode>, EpisodeError> {
+        page::episodes(self, since).await.map_err(|err| match err {
+            WebtoonError::ClientError(client_error) => EpisodeError::ClientError(client_error),
+            error => EpisodeError::Unexpected(error.into()),
+        })
+    }
+
+    /// Returns the episodes after `latest`, the newest public one, that the session user can view, like fast-pass
+    /// episodes they bought.
+    ///
+    /// Episodes are checked until the first one that doesn't exist, skipping those the user hasn't unlocked.
+    async fn unlocked_episodes(&self, latest: u16) -> Result<Vec<Episode>, EpisodeError> {
+        let mut unlocked = Vec::new();
+
+        for number in latest.saturating_add(1)..=u16::MAX {
+            self.client.check_cancelled()?;
+
+            let mut episode = Episode::new(self, number);
+
+            if !episode.exists().await? {
+                break;
+            }
+
+            if episode.scrape_as_session_user().await? {
+                episode.published_status = Some(episode::PublishedStatus::Published);
+                unlocked.push(episode);
+            }
+        }
+
+        Ok(unlocked)
+    }
+
     /// Constructs an `Episode` if it exists.
     ///
     /// However, there are important caveats to be aware of when using this method instead of `episodes`.
@@ -602,10 +895,7 @@ impl Webtoon {
     pub async fn episode(&self, number: u16) -> Result<Option<Episode>, EpisodeError> {
         let episode = Episode::new(self, number);
 
-        if !episode.exists().await.map_err(|err| match err {
-            PostError::ClientError(client_error) => EpisodeError::ClientError(client_error),
-            error => EpisodeError::Unexpected(error.into()),
-        })? {
+        if !episode.exists().await? {
             return Ok(None);
         }
 
@@ -655,10 +945,43 @@ impl Webtoon {
     /// - `EpisodeError::ClientError`: If there is an issue with the client during episode retrieval.
     /// - `EpisodeError::Unexpected`: If an unexpected error occurs during the process.
     pub async fn likes(&self) -> Result<u32, EpisodeError> {
+        self.likes_with_progress(|_| {}).await
+    }
+
+    /// Retrieves the total number of likes for all episodes of the `Webtoon`, reporting progress as it goes.
+    ///
+    /// Behaves like [`Webtoon::likes`], but `on_episode` is called after each episode's likes have been added, with the
+    /// number of episodes completed so far. As the total number of episodes isn't known ahead of time, this is a count
+    /// rather than a fraction.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{ Client, Language, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// let total_likes = webtoon
+    ///     .likes_with_progress(|completed| eprintln!("{completed} episodes done"))
+    ///     .await?;
+    /// println!("Total likes for the webtoon: {}", total_likes);
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// Same as [`Webtoon::likes`].
+    pub async fn likes_with_progress(&self, on_episode: impl Fn(u16)) -> Result<u32, EpisodeError> {
         let mut likes = 0;
+        let mut completed: u16 = 0;
         for number in 1.. {
             if let Some(episode) = self.episode(number).await? {
                 likes += episode.likes().await?;
+                completed += 1;
+                on_episode(completed);
             } else {
                 break;
             }
@@ -667,6 +990,142 @@ impl Webtoon {
         Ok(likes)
     }
 
+    /// Retrieves the likes of every episode of the `Webtoon`, keyed by episode number.
+    ///
+    /// Unlike [`Webtoon::likes`], which makes a request per episode, the likes API is asked for 20 episodes at a time.
+    ///
+    /// ### Behavior
+    ///
+    /// - The highest episode number in [`Webtoon::episodes`] is used as the known range, and every number up to it is
+    ///   in the map, even deleted ones, which will have `0` likes.
+    /// - Past that range, batches keep being requested for episodes behind ads or fast-pass, and only episodes with
+    ///   likes are added. This stops at the first batch without any likes.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{ Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// for (number, likes) in webtoon.episode_likes_map().await? {
+    ///     println!("episode {number}: {likes} likes");
+    /// }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `EpisodeError::ClientError`: If there is an issue with the client while getting the episodes or their likes.
+    /// - `EpisodeError::Unexpected`: If the likes API response could not be parsed.
+    pub async fn episode_likes_map(&self) -> Result<BTreeMap<u16, u32>, EpisodeError> {
+        const BATCH: u16 = 20;
+
+        let known = self
+            .episodes()
+            .await?
+            .into_iter()
+            .map(|episode| episode.number())
+            .max()
+            .unwrap_or_default();
+
+        let mut map = BTreeMap::new();
+        let mut start: u16 = 1;
+
+        loop {
+            let end = start.saturating_add(BATCH - 1);
+            let numbers = (start..=end).collect::<Vec<u16>>();
+
+            let response = self
+                .client
+                .get_likes_for_episodes(self, &numbers)
+                .await?
+                .text()
+                .await?;
+
+            let api = serde_json::from_str::<Likes>(&response).context(response)?;
+
+            let mut any = false;
+            for (reaction, position) in api.result.contents.iter().zip(&numbers) {
+                let number = reaction.episode().unwrap_or(*position);
+                let likes = reaction.count();
+
+                if number <= known || likes > 0 {
+                    map.insert(number, likes);
+                }
+
+                any |= likes > 0;
+            }
+
+            for number in numbers.into_iter().filter(|number| *number <= known) {
+                map.entry(number).or_insert(0);
+            }
+
+            if (end >= known && !any) || end == u16::MAX {
+                break;
+            }
+
+            start = end + 1;
+        }
+
+        Ok(map)
+    }
+
+    /// Retrieves the views, subscribers, rating, and likes of the `Webtoon` together, as a [`StatsSnapshot`].
+    ///
+    /// The cache is evicted before starting, so that every value comes from the same fresh page, rather than some
+    /// values coming from a page scraped earlier. `at` is recorded when the snapshot is started.
+    ///
+    /// This has the same costs as calling each method separately, with [`Webtoon::likes`] being the most expensive as it
+    /// goes through every episode.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{ Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// let snapshot = webtoon.stats_snapshot().await?;
+    /// println!("{}: {} subscribers", snapshot.at(), snapshot.subscribers());
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `WebtoonError::ClientError`: If there is an issue with the client during retrieval.
+    /// - `WebtoonError::Unexpected`: If an unexpected error occurs while scraping any of the values.
+    pub async fn stats_snapshot(&self) -> Result<StatsSnapshot, WebtoonError> {
+        let at = chrono::Utc::now();
+
+        self.evict_cache().await;
+
+        let rating = self.rating().await?;
+        let subscribers = self.subscribers().await?;
+
+        let episode_error = |err: EpisodeError| match err {
+            EpisodeError::ClientError(client_error) => WebtoonError::ClientError(client_error),
+            error => WebtoonError::Unexpected(error.into()),
+        };
+
+        let views = self.views().await.map_err(episode_error)?;
+        let likes = self.likes().await.map_err(episode_error)?;
+
+        Ok(StatsSnapshot {
+            views,
+            subscribers,
+            rating,
+            likes,
+            at,
+        })
+    }
+
     /// Retrieves all posts(top level comments) for every episode of the current `Webtoon`.
     ///
     /// This method can return more posts than what is publicly available on the main webtoon page, as it includes certain deleted posts as well as those visible to all users.
@@ -712,7 +1171,37 @@ impl Webtoon {
     /// - `PostError::ClientError`: If there is an issue with the client during episode or post retrieval.
     /// - `PostError::Unexpected`: If an unexpected error occurs during the process.
     pub async fn posts(&self) -> Result<Posts, PostError> {
+        self.posts_with_progress(|_| {}).await
+    }
+
+    /// Retrieves all posts(top level comments) for every episode of the current `Webtoon`, reporting progress as it goes.
+    ///
+    /// Behaves like [`Webtoon::posts`], but `on_episode` is called once an episode's posts have all been gathered, with
+    /// the number of episodes completed so far.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{ Client, Language, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// let posts = webtoon
+    ///     .posts_with_progress(|completed| eprintln!("{completed} episodes done"))
+    ///     .await?;
+    /// println!("got {} posts", posts.as_slice().len());
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// Same as [`Webtoon::posts`].
+    pub async fn posts_with_progress(&self, on_episode: impl Fn(u16)) -> Result<Posts, PostError> {
         let mut posts = Vec::new();
+        let mut completed: u16 = 0;
 
         for number in 1.. {
             if let Some(episode) = self.episode(number).await.map_err(|err| match err {
@@ -720,6 +1209,8 @@ impl Webtoon {
                 error => PostError::Unexpected(error.into()),
             })? {
                 posts.extend_from_slice(episode.posts().await?.as_slice());
+                completed += 1;
+                on_episode(completed);
             } else {
                 break;
             }
@@ -728,12 +1219,96 @@ impl Webtoon {
         Ok(posts.into())
     }
 
+    /// Returns the posters that gave the most super likes across every episode, along with how many they gave in total.
+    ///
+    /// At most `limit` posters are returned, biggest supporter first. This goes through the same posts as
+    /// [`Webtoon::posts`], so it has the same cost. See [`Posts::super_likers`] for how the totals are worked out.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// for (poster, super_likes) in webtoon.top_super_likers(10).await? {
+    ///     println!("{}: {super_likes}", poster.username());
+    /// }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// Same as [`Webtoon::posts`].
+    pub async fn top_super_likers(&self, limit: usize) -> Result<Vec<(Poster, u32)>, PostError> {
+        let mut super_likers = self.posts().await?.super_likers();
+
+        super_likers.truncate(limit);
+
+        Ok(super_likers)
+    }
+
+    /// Retrieves all posts for every episode of the current `Webtoon`, continuing past episodes whose posts fail to load.
+    ///
+    /// Behaves like [`Webtoon::posts`], but a failure while fetching one episode's posts does not throw away the work
+    /// done for the others. The posts that could be gathered are returned alongside the episode numbers that failed and
+    /// their errors, which can then be retried individually with [`Episode::posts`](episode::Episode::posts).
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{ Client, Language, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::new();
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// let (posts, failed) = webtoon.posts_lenient().await?;
+    ///
+    /// println!("got {} posts", posts.as_slice().len());
+    ///
+    /// for (number, error) in failed {
+    ///     eprintln!("episode {number} failed: {error}");
+    /// }
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// Only failing to find out whether an episode exists is returned as an error, as without it there is no way to
+    /// know where the episodes end. Errors from an episode's posts are collected in the returned list instead.
+    pub async fn posts_lenient(&self) -> Result<(Posts, Vec<(u16, PostError)>), PostError> {
+        let mut posts = Vec::new();
+        let mut failed = Vec::new();
+
+        for number in 1.. {
+            let Some(episode) = self.episode(number).await.map_err(|err| match err {
+                EpisodeError::ClientError(client_error) => PostError::ClientError(client_error),
+                error => PostError::Unexpected(error.into()),
+            })?
+            else {
+                break;
+            };
+
+            match episode.posts().await {
+                Ok(episode_posts) => posts.extend_from_slice(episode_posts.as_slice()),
+                Err(err) => failed.push((number, err)),
+            }
+        }
+
+        Ok((posts.into(), failed))
+    }
+
     /// Retrieves the RSS feed information for the current `Webtoon`.
     ///
     /// This includes data for recently published episodes, but excludes episodes that are behind fast-pass or ad walls.
     /// The feed is optimized to provide a more user-friendly representation of the webtoon’s episodes.
     ///
-    /// **CURRENTLY ONLY ENGLISH I SUPPORTED**
+    /// Works for every [`Language`], with the localized publish dates of the feed parsed to UTC.
     ///
     /// ### Behavior
     ///
@@ -768,7 +1343,7 @@ impl Webtoon {
     /// ### Errors
     ///
     /// - `WebtoonError::ClientError`: If there is an issue with the client while retrieving the RSS feed.
-    /// - `WebtoonError::Unexpected`: If an unexpected error occurs during the process.
+    /// - `WebtoonError::Unexpected`: If the feed could not be parsed, such as a publish date with an unknown month name.
     #[cfg(feature = "rss")]
     pub async fn rss(&self) -> Result<Rss, WebtoonError> {
         rss::feed(self).await
@@ -888,6 +1463,54 @@ impl Webtoon {
         Ok(())
     }
 
+    /// Subscribes the current user to the `Webtoon`, also setting whether they should be notified when a new episode is released.
+    ///
+    /// [`Webtoon::subscribe`] leaves the notification preference untouched. This method first subscribes, if not already
+    /// subscribed, and then sets the notification preference to `notify`.
+    ///
+    /// ### Behavior
+    ///
+    /// - **Creator Check**: If the user is the creator of the webtoon, the method does nothing and returns `Ok(())`.
+    /// - **Already Subscribed**: The notification preference is still updated to match `notify`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # use webtoon::platform::webtoons::{ Client, Type, errors::Error};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// # let client = Client::with_session("my-session");
+    /// # if let Some(webtoon) = client.webtoon(843910, Type::Canvas).await? {
+    /// webtoon.subscribe_with_notifications(true).await?;
+    /// # }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Errors
+    ///
+    /// - `WebtoonError::ClientError(ClientError::InvalidSession)`: If the session is invalid or expired.
+    /// - `WebtoonError::ClientError(ClientError::NoSessionProvided)`: If no user session was provided.
+    /// - `WebtoonError::Unexpected`: If an unexpected issue occurs during the process of subscribing.
+    pub async fn subscribe_with_notifications(&self, notify: bool) -> Result<(), WebtoonError> {
+        let user = self.client.get_user_info_for_webtoon(self).await?;
+
+        // Can't sub to own webtoon
+        if user.is_webtoon_creator() {
+            return Ok(());
+        }
+
+        if !user.favorite {
+            self.client.post_subscribe_to_webtoon(self).await?;
+        }
+
+        self.client
+            .post_set_notifications_for_webtoon(self, notify)
+            .await?;
+
+        Ok(())
+    }
+
     /// Unsubscribes the current user from the `Webtoon`, if currently subscribed.
     ///
     /// ### Behavior
@@ -1000,7 +1623,7 @@ impl Webtoon {
             }
         );
 
-        let response = client.http.get(&url).send().await?;
+        let response = client.get(&url).send_traced().await?;
 
         // Webtoon doesn't exist
         if response.status() == 404 {
@@ -1042,6 +1665,7 @@ impl Webtoon {
             scope,
             slug: Arc::from(slug),
             page: Arc::new(Mutex::new(None)),
+            genres: None,
         };
 
         Ok(webtoon)
@@ -1088,6 +1712,7 @@ impl Webtoon {
             slug: Arc::from(slug),
             id,
             page: Arc::new(Mutex::new(None)),
+            genres: None,
         };
 
         Ok(webtoon)
@@ -1100,6 +1725,51 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn should_be_equal_by_language_type_and_id() {
+        use std::collections::HashSet;
+
+        let client = Client::new();
+
+        let tower_of_god = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/en/fantasy/tower-of-god/list?title_no=95",
+            &client,
+        )
+        .unwrap();
+
+        // Same webtoon, different slug
+        let renamed = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/en/fantasy/tog/list?title_no=95",
+            &client,
+        )
+        .unwrap();
+
+        let spanish = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/es/fantasy/tower-of-god/list?title_no=95",
+            &client,
+        )
+        .unwrap();
+
+        let canvas = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/en/canvas/tower-of-god/list?title_no=95",
+            &client,
+        )
+        .unwrap();
+
+        assert_eq!(tower_of_god, renamed);
+        assert_ne!(tower_of_god, spanish);
+        assert_ne!(tower_of_god, canvas);
+
+        #[allow(
+            clippy::mutable_key_type,
+            reason = "`Webtoon` has interior mutability, but the `Hash` implementation only uses the language, type, and id"
+        )]
+        let set: HashSet<Webtoon> = [tower_of_god, renamed, spanish, canvas]
+            .into_iter()
+            .collect();
+        assert_eq!(3, set.len());
+    }
+
     #[tokio::test]
     async fn should_make_webtoon_from_url() {
         let webtoon = Webtoon::from_url_with_client(
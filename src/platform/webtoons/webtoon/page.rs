@@ -3,6 +3,7 @@ mod en;
 mod es;
 mod fr;
 mod id;
+mod mobile;
 mod th;
 mod zh;
 
@@ -12,7 +13,7 @@ use url::Url;
 
 use crate::platform::webtoons::{
     creator::Creator,
-    meta::{Genre, Language},
+    meta::{Genre, Language, Scope},
     originals::Release,
     Webtoon,
 };
@@ -23,6 +24,7 @@ use super::{episode::Episode, WebtoonError};
 #[derive(Debug)]
 pub struct Page {
     title: String,
+    original_title: Option<String>,
     creators: Vec<Creator>,
     genres: Vec<Genre>,
     summary: String,
@@ -36,14 +38,14 @@ pub struct Page {
 }
 
 #[inline]
-pub async fn scrape<'a>(webtoon: &Webtoon) -> Result<Page, WebtoonError> {
+pub async fn scrape(webtoon: &Webtoon) -> Result<Page, WebtoonError> {
     let response = webtoon.client.get_webtoon_page(webtoon, None).await?;
 
     let document = response.text().await?;
 
     let html = Html::parse_document(&document);
 
-    let page = match webtoon.language {
+    let mut page = match webtoon.language {
         Language::En => en::page(&html, webtoon)?,
         Language::Zh => zh::page(&html, webtoon)?,
         Language::Th => th::page(&html, webtoon)?,
@@ -53,15 +55,48 @@ pub async fn scrape<'a>(webtoon: &Webtoon) -> Result<Page, WebtoonError> {
         Language::De => de::page(&html, webtoon)?,
     };
 
+    merge_scope_genre(&mut page.genres, webtoon.scope);
+
     Ok(page)
 }
 
+/// Adds the genre from the URL the `Webtoon` was made from, as that can be one the page itself doesn't list, like
+/// when coming from the originals landing page.
+///
+/// The primary genre stays first and the rest stay in slug order, so a genre already on the page is not added twice.
+fn merge_scope_genre(genres: &mut Vec<Genre>, scope: Scope) {
+    let Scope::Original(genre) = scope else {
+        return;
+    };
+
+    if genres.contains(&genre) {
+        return;
+    }
+
+    if genres.is_empty() {
+        genres.push(genre);
+        return;
+    }
+
+    let position = genres[1..]
+        .iter()
+        .position(|other| other.as_slug() > genre.as_slug())
+        .map_or(genres.len(), |position| position + 1);
+
+    genres.insert(position, genre);
+}
+
 impl Page {
     #[inline]
     pub(crate) fn title(&self) -> &str {
         &self.title
     }
 
+    #[inline]
+    pub(crate) fn original_title(&self) -> Option<&str> {
+        self.original_title.as_deref()
+    }
+
     #[inline]
     pub(crate) fn creators(&self) -> &[Creator] {
         &self.creators
@@ -108,7 +143,29 @@ impl Page {
     }
 }
 
-pub(super) async fn episodes(webtoon: &Webtoon) -> Result<Vec<Episode>, WebtoonError> {
+/// Scrapes the public episode list.
+///
+/// With `since`, only episodes with a number past it are kept, and as the list goes from newest to oldest, no more
+/// pages are requested once an episode at or before it is reached.
+///
+/// If the desktop markup no longer matches what is expected, the list is gotten from the mobile API instead. Should
+/// that fail as well, the error from the desktop scrape is returned.
+pub(super) async fn episodes(
+    webtoon: &Webtoon,
+    since: Option<u16>,
+) -> Result<Vec<Episode>, WebtoonError> {
+    match desktop_episodes(webtoon, since).await {
+        Err(WebtoonError::Unexpected(error)) => mobile::episodes(webtoon, since)
+            .await
+            .map_err(|_| WebtoonError::Unexpected(error)),
+        result => result,
+    }
+}
+
+async fn desktop_episodes(
+    webtoon: &Webtoon,
+    since: Option<u16>,
+) -> Result<Vec<Episode>, WebtoonError> {
     // TODO: If it ever becomes possible to detect the last page via a redirect or some other mechanism, the initial
     // scrape shouldn't be needed anymore, and can just be iterated over with `1..` until the last page
 
@@ -127,10 +184,14 @@ pub(super) async fn episodes(webtoon: &Webtoon) -> Result<Vec<Episode>, WebtoonE
     let mut episodes = Vec::with_capacity(pages as usize * 10);
 
     for page in 1..=pages {
+        webtoon.client.check_cancelled()?;
+
         let response = webtoon.client.get_webtoon_page(webtoon, Some(page)).await?;
 
         let html = Html::parse_document(&response.text().await?);
 
+        let mut reached_known = false;
+
         for element in html.select(&selector) {
             let episode = match webtoon.language {
                 Language::En => en::episode(&element, webtoon)?,
@@ -142,9 +203,18 @@ pub(super) async fn episodes(webtoon: &Webtoon) -> Result<Vec<Episode>, WebtoonE
                 Language::De => de::episode(&element, webtoon)?,
             };
 
+            if since.is_some_and(|since| episode.number() <= since) {
+                reached_known = true;
+                continue;
+            }
+
             episodes.push(episode);
         }
 
+        if reached_known {
+            break;
+        }
+
         // This page never returns a rate limt response, it just silently fails, leading to missed pages.
         tokio::time::sleep(Duration::from_millis(250)).await;
     }
@@ -154,3 +224,46 @@ pub(super) async fn episodes(webtoon: &Webtoon) -> Result<Vec<Episode>, WebtoonE
 
     Ok(episodes)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::platform::webtoons::Client;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_merge_genre_from_url_into_page_genres() {
+        let webtoon = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/en/romance/tower-of-god/list?title_no=95",
+            &Client::new(),
+        )
+        .unwrap();
+
+        let mut genres = vec![Genre::Fantasy, Genre::Action, Genre::Thriller];
+        merge_scope_genre(&mut genres, webtoon.scope);
+        assert_eq!(
+            vec![
+                Genre::Fantasy,
+                Genre::Action,
+                Genre::Romance,
+                Genre::Thriller
+            ],
+            genres
+        );
+
+        merge_scope_genre(&mut genres, webtoon.scope);
+        assert_eq!(
+            vec![
+                Genre::Fantasy,
+                Genre::Action,
+                Genre::Romance,
+                Genre::Thriller
+            ],
+            genres
+        );
+
+        let mut genres = vec![Genre::Romance, Genre::Action];
+        merge_scope_genre(&mut genres, webtoon.scope);
+        assert_eq!(vec![Genre::Romance, Genre::Action], genres);
+    }
+}
@@ -1,13 +1,14 @@
-use std::{str::FromStr, sync::Arc};
+use std::{collections::HashSet, str::FromStr, sync::Arc};
 
 use anyhow::Context;
 use chrono::{DateTime, Utc};
 use regex::Regex;
-use scraper::{ElementRef, Html, Selector};
+use scraper::{ElementRef, Html, Node, Selector};
 use tokio::sync::Mutex;
 use url::Url;
 
 use crate::platform::webtoons::{
+    cdn,
     creator::Creator,
     meta::{Genre, Scope},
     originals::Release,
@@ -21,7 +22,8 @@ pub(super) fn page(html: &Html, webtoon: &Webtoon) -> Result<Page, WebtoonError>
     let page = match webtoon.scope {
         Scope::Original(_) => Page {
             title: title(html)?,
-            creators: creators(html, &webtoon.client)?,
+            original_title: original_title(html),
+            creators: creators(html, &webtoon.client, webtoon.language)?,
             genres: genres(html)?,
             summary: summary(html)?,
             views: views(html)?,
@@ -34,7 +36,8 @@ pub(super) fn page(html: &Html, webtoon: &Webtoon) -> Result<Page, WebtoonError>
         },
         Scope::Canvas => Page {
             title: title(html)?,
-            creators: creators(html, &webtoon.client)?,
+            original_title: original_title(html),
+            creators: creators(html, &webtoon.client, webtoon.language)?,
             genres: genres(html)?,
             summary: summary(html)?,
             views: views(html)?,
@@ -80,7 +83,49 @@ pub(super) fn title(html: &Html) -> Result<String, WebtoonError> {
     Ok(title)
 }
 
-pub(super) fn creators(html: &Html, client: &Client) -> Result<Vec<Creator>, WebtoonError> {
+/// Finds the original, untranslated, title in the page's metadata.
+///
+/// There is no one tag for this, and most pages don't have it at all, so any `<meta>` whose `property` or `name`
+/// mentions both "original" and "title" is taken. A value that is the same as the localized title is ignored.
+pub(super) fn original_title(html: &Html) -> Option<String> {
+    let selector = Selector::parse("meta[content]") //
+        .expect("`meta[content]` should be a valid selector");
+
+    let localized = title(html).ok();
+
+    html.select(&selector).find_map(|meta| {
+        let key = meta
+            .attr("property")
+            .or_else(|| meta.attr("name"))?
+            .to_lowercase();
+
+        if !key.contains("original") || !key.contains("title") {
+            return None;
+        }
+
+        let content = meta
+            .attr("content")?
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if content.is_empty()
+            || localized
+                .as_deref()
+                .is_some_and(|localized| localized.eq_ignore_ascii_case(&content))
+        {
+            return None;
+        }
+
+        Some(content)
+    })
+}
+
+pub(super) fn creators(
+    html: &Html,
+    client: &Client,
+    language: Language,
+) -> Result<Vec<Creator>, WebtoonError> {
     // NOTE: Some creators have a little popup when you click on a button. Other have a dedicated page on the platform.
     // All instances have a `div.author_area` but the ones with a button have the name located directly in this.
     // Other instances have a nested <a> tag with the name.
@@ -120,7 +165,7 @@ pub(super) fn creators(html: &Html, client: &Client) -> Result<Vec<Creator>, Web
         let profile = url
             .path_segments()
             .context("`href` should have path segments")?
-            .last()
+            .next_back()
             .unwrap();
 
         let mut username = String::new();
@@ -139,7 +184,7 @@ pub(super) fn creators(html: &Html, client: &Client) -> Result<Vec<Creator>, Web
 
         creators.push(Creator {
             client: client.clone(),
-            language: Language::En,
+            language,
             profile: Some(profile.into()),
             username,
             page: Arc::new(Mutex::new(None)),
@@ -174,7 +219,7 @@ pub(super) fn creators(html: &Html, client: &Client) -> Result<Vec<Creator>, Web
 
                 creators.push(Creator {
                     client: client.clone(),
-                    language: Language::En,
+                    language,
                     profile: None,
                     username: username.trim().into(),
                     page: Arc::new(Mutex::new(None)),
@@ -183,9 +228,29 @@ pub(super) fn creators(html: &Html, client: &Client) -> Result<Vec<Creator>, Web
         }
     }
 
+    dedup_creators(&mut creators);
+
     Ok(creators)
 }
 
+/// Removes creators credited more than once, like someone who is both the writer and the artist.
+///
+/// The page credits the writer first and the artist second, so the first credit is the one kept, which keeps that
+/// order stable. Creators with an account are the same when their profiles match, otherwise their usernames are
+/// compared, ignoring case.
+fn dedup_creators(creators: &mut Vec<Creator>) {
+    let mut seen = HashSet::with_capacity(creators.len());
+
+    creators.retain(|creator| {
+        let key = match &creator.profile {
+            Some(profile) => format!("profile:{profile}"),
+            None => format!("username:{}", creator.username.to_lowercase()),
+        };
+
+        seen.insert(key)
+    });
+}
+
 pub(super) fn genres(html: &Html) -> Result<Vec<Genre>, WebtoonError> {
     // `h2.genre` for originals and `p.genre` for canvas
     // Doing just `.genre` gets the all instances of the class
@@ -198,15 +263,35 @@ pub(super) fn genres(html: &Html) -> Result<Vec<Genre>, WebtoonError> {
         let genre = selected
             .text()
             .next()
-            .context("`.info>.genre` was found but no text was present")?;
-
-        genres.push(Genre::from_str(genre).map_err(|err| WebtoonError::Unexpected(err.into()))?);
+            .context("`.info>.genre` was found but no text was present")?
+            .trim();
+
+        // Canvas creators see the genre label in the page's language, which may not be one of the known names. The
+        // `g_{slug}` class is the same in every language, so it is tried before giving up.
+        let genre = match Genre::from_str(genre) {
+            Ok(genre) => genre,
+            Err(err) => selected
+                .value()
+                .classes()
+                .filter_map(|class| class.strip_prefix("g_"))
+                .find_map(|slug| Genre::from_str(&slug.replace('_', "-")).ok())
+                .ok_or_else(|| WebtoonError::Unexpected(err.into()))?,
+        };
+
+        genres.push(genre);
     }
 
     if genres.is_empty() {
         return Err(WebtoonError::NoGenre);
     }
 
+    // The first genre on the page is the primary one. The rest are sorted so the order doesn't depend on the markup.
+    let primary = genres.remove(0);
+    genres.retain(|genre| *genre != primary);
+    genres.sort_unstable_by_key(|genre| genre.as_slug());
+    genres.dedup();
+    genres.insert(0, primary);
+
     Ok(genres)
 }
 
@@ -320,26 +405,42 @@ pub(super) fn summary(html: &Html) -> Result<String, WebtoonError> {
     let selector = Selector::parse(r"p.summary") //
         .expect("`p.summary` should be a valid selector");
 
-    let text = html
+    let element = html
         .select(&selector)
         .next()
-        .context("`p.summary` is missing: webtoons requires a summary")?
-        .text()
-        .next()
-        .context("`p.summary` was found but no text was present")?;
+        .context("`p.summary` is missing: webtoons requires a summary")?;
 
-    let mut summary = String::new();
+    // `<br>` is the only line break the creator controls; every other newline is just page formatting.
+    let mut lines = vec![String::new()];
 
-    // Gets rid of any weird formatting, such as newlines and tabs being in the middle of the summary.
-    for word in text.split_whitespace() {
-        summary.push_str(word);
-        summary.push(' ');
+    for node in element.descendants() {
+        match node.value() {
+            Node::Text(text) => {
+                if let Some(line) = lines.last_mut() {
+                    line.push_str(text);
+                }
+            }
+            Node::Element(element) if element.name() == "br" => lines.push(String::new()),
+            _ => {}
+        }
     }
 
-    // Removes the final spacing at the end while keeping it a string.
-    summary.pop();
+    // Gets rid of any weird formatting, such as newlines and tabs being in the middle of the summary.
+    let lines: Vec<String> = lines
+        .iter()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect();
+
+    let summary = lines.join("\n").trim_matches('\n').to_string();
+
+    if summary.is_empty() {
+        return Err(WebtoonError::Unexpected(anyhow::anyhow!(
+            "`p.summary` was found but no text was present"
+        )));
+    }
 
-    Ok(summary)
+    // Some summaries are escaped twice, leaving entities like `&amp;` even after the html is parsed.
+    Ok(html_escape::decode_html_entities(&summary).to_string())
 }
 
 pub fn original_thumbnail(html: &Html) -> Result<Url, WebtoonError> {
@@ -362,10 +463,7 @@ pub fn original_thumbnail(html: &Html) -> Result<Url, WebtoonError> {
 
     let mut thumbnail = Url::parse(&cap["url"])?;
 
-    thumbnail
-        // This host doesn't need a `referer` header to see the image.
-        .set_host(Some("swebtoon-phinf.pstatic.net"))
-        .expect("`swebtoon-phinf.pstatic.net` should be a valid host");
+    cdn::rewrite_host(&mut thumbnail);
 
     Ok(thumbnail)
 }
@@ -386,10 +484,7 @@ pub(super) fn canvas_thumbnail(html: &Html) -> Result<Url, WebtoonError> {
 
     let mut thumbnail = Url::parse(url)?;
 
-    thumbnail
-        // This host doesn't need a `referer` header to see the image.
-        .set_host(Some("swebtoon-phinf.pstatic.net"))
-        .expect("`swebtoon-phinf.pstatic.net` should be a valid host");
+    cdn::rewrite_host(&mut thumbnail);
 
     Ok(thumbnail)
 }
@@ -408,10 +503,7 @@ pub(super) fn banner(html: &Html) -> Result<Url, WebtoonError> {
 
     let mut banner = Url::parse(url)?;
 
-    banner
-        // This host doesn't need a `referer` header to see the image.
-        .set_host(Some("swebtoon-phinf.pstatic.net"))
-        .expect("`swebtoon-phinf.pstatic.net` should be a valid host");
+    cdn::rewrite_host(&mut banner);
 
     Ok(banner)
 }
@@ -479,8 +571,10 @@ pub(super) fn episode(
         season: Arc::new(Mutex::new(super::super::episode::season(&title))),
         title: Arc::new(Mutex::new(Some(title))),
         number,
+        display_number: episode_display_number(element),
         published: Some(published),
         page: Arc::new(Mutex::new(None)),
+        comments_and_replies: Arc::new(Mutex::new(None)),
         views: None,
         // NOTE: Impossible to say from this page. In general any random Original episode would have been
         // behind fast-pass, but the initial release episodes which never were would be impossible to tell.
@@ -507,6 +601,22 @@ pub(super) fn episode_title(episode: &ElementRef<'_>) -> Result<String, WebtoonE
     Ok(escaped.to_string())
 }
 
+/// Gets the `#N` shown next to an episode in the list, which skips over deleted episodes unlike `data-episode-no`.
+pub(super) fn episode_display_number(episode: &ElementRef<'_>) -> Option<u16> {
+    let selector = Selector::parse("span.tx") //
+        .expect("`span.tx` should be a valid selector");
+
+    episode
+        .select(&selector)
+        .next()?
+        .text()
+        .next()?
+        .trim()
+        .strip_prefix('#')?
+        .parse::<u16>()
+        .ok()
+}
+
 // NOTE: Currently forces all dates to be at 02:00 UTC as thats when the originals get released.
 // For more accurate times, must have a session.
 fn episode_published_date(episode: &ElementRef<'_>) -> Result<DateTime<Utc>, WebtoonError> {
@@ -539,3 +649,99 @@ fn episode_published_date(episode: &ElementRef<'_>) -> Result<DateTime<Utc>, Web
 fn episode_likes(_episode: &ElementRef<'_>) -> Result<u32, WebtoonError> {
     unimplemented!()
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_dedup_genres_keeping_primary_first() {
+        let html = Html::parse_document(
+            r#"<div class="info"><h2 class="genre">Fantasy</h2><p class="genre">Romance</p><p class="genre">Fantasy</p><p class="genre">Action</p><p class="genre">Romance</p></div>"#,
+        );
+
+        assert_eq!(
+            vec![Genre::Fantasy, Genre::Action, Genre::Romance],
+            genres(&html).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_find_canvas_genre() {
+        let html = Html::parse_document(
+            r#"<div class="info challenge"><p class="genre g_comedy">Comedy</p><h3 class="subj">Testing Service</h3></div>"#,
+        );
+
+        assert_eq!(vec![Genre::Comedy], genres(&html).unwrap());
+
+        // Label not in a known language, but the class still names the genre.
+        let html = Html::parse_document(
+            r#"<div class="info challenge"><p class="genre g_slice_of_life">日常</p></div>"#,
+        );
+
+        assert_eq!(vec![Genre::SliceOfLife], genres(&html).unwrap());
+    }
+
+    #[test]
+    fn should_dedup_creators_keeping_credit_order() {
+        let html = Html::parse_document(
+            r#"<div class="author_area"><a class="author" href="https://www.webtoons.com/en/creator/abc12">Writer Person</a>, <a class="author" href="https://www.webtoons.com/en/creator/def34">Artist Person</a>, <a class="author" href="https://www.webtoons.com/en/creator/abc12">Writer Person</a>, Adapter, adapter ...<button>author info</button></div>"#,
+        );
+
+        let creators = creators(&html, &Client::new(), Language::Es).unwrap();
+
+        assert_eq!(
+            vec!["Writer Person", "Artist Person", "Adapter"],
+            creators
+                .iter()
+                .map(|creator| creator.username.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert!(creators
+            .iter()
+            .all(|creator| creator.language == Language::Es));
+    }
+
+    #[test]
+    fn should_parse_episode_display_number() {
+        let html = Html::parse_fragment(
+            r#"<ul><li class="_episodeItem" data-episode-no="45"><a href="https://www.webtoons.com/en/fantasy/tower-of-god/episode-45/viewer?title_no=95&episode_no=45"><span class="subj"><span>Episode 42</span></span><span class="tx">#42</span></a></li></ul>"#,
+        );
+
+        let selector = Selector::parse("li._episodeItem").unwrap();
+        let element = html.select(&selector).next().unwrap();
+
+        assert_eq!(Some(42), episode_display_number(&element));
+    }
+
+    #[test]
+    fn should_find_original_title_in_metadata() {
+        let html = Html::parse_document(
+            r#"<html><head><meta property="og:title" content="The Remarried Empress"><meta property="com-linewebtoon:webtoon:originalTitle" content="재혼 황후"></head><body><h1 class="subj">The Remarried Empress</h1></body></html>"#,
+        );
+        assert_eq!(Some("재혼 황후".to_string()), original_title(&html));
+
+        let html = Html::parse_document(
+            r#"<html><head><meta name="originalTitle" content="Tower of God"></head><body><h1 class="subj">Tower of God</h1></body></html>"#,
+        );
+        assert_eq!(None, original_title(&html));
+
+        let html = Html::parse_document(
+            r#"<html><head><meta property="originalTitle" content="Tom &amp;amp; Jerry"></head><body><h1 class="subj">Tom and Jerry</h1></body></html>"#,
+        );
+        assert_eq!(Some("Tom &amp; Jerry".to_string()), original_title(&html));
+    }
+
+    #[test]
+    fn should_clean_up_summary_markup() {
+        let html = Html::parse_document(
+            "<p class=\"summary\">\n\t\tTom &amp;amp; Jerry&#39;s   big\n adventure.<br>Season 2<br/><br>  Now &lt;daily&gt;!\n</p>",
+        );
+
+        assert_eq!(
+            "Tom & Jerry's big adventure.\nSeason 2\n\nNow <daily>!",
+            summary(&html).unwrap()
+        );
+    }
+}
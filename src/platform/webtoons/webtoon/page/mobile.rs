@@ -0,0 +1,114 @@
+//! Episode list from the JSON API behind `m.webtoons.com`, used when the desktop markup can't be scraped.
+
+use std::sync::Arc;
+
+use anyhow::Context;
+use chrono::DateTime;
+use tokio::sync::Mutex;
+
+use crate::platform::webtoons::{
+    webtoon::{
+        episode::{self, Episode, PublishedStatus},
+        WebtoonError,
+    },
+    Webtoon,
+};
+
+pub(super) async fn episodes(
+    webtoon: &Webtoon,
+    since: Option<u16>,
+) -> Result<Vec<Episode>, WebtoonError> {
+    let response = webtoon.client.get_mobile_episodes(webtoon).await?;
+
+    let api = response
+        .json::<api::Response>()
+        .await
+        .context("mobile episode list should be valid json")?;
+
+    let mut episodes = api
+        .result
+        .episode_list
+        .into_iter()
+        .filter(|episode| since.is_none_or(|since| episode.episode_no > since))
+        .map(|episode| self::episode(webtoon, episode))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    episodes.sort_unstable_by_key(Episode::number);
+
+    Ok(episodes)
+}
+
+fn episode(webtoon: &Webtoon, episode: api::Episode) -> Result<Episode, WebtoonError> {
+    let title = html_escape::decode_html_entities(&episode.episode_title).to_string();
+
+    let published = DateTime::from_timestamp_millis(episode.exposure_date_millis)
+        .context("`exposureDateMillis` should be a valid timestamp")?;
+
+    Ok(Episode {
+        webtoon: webtoon.clone(),
+        season: Arc::new(Mutex::new(episode::season(&title))),
+        title: Arc::new(Mutex::new(Some(title))),
+        number: episode.episode_no,
+        display_number: None,
+        published: Some(published),
+        page: Arc::new(Mutex::new(None)),
+        comments_and_replies: Arc::new(Mutex::new(None)),
+        views: None,
+        ad_status: None,
+        published_status: Some(PublishedStatus::Published),
+    })
+}
+
+mod api {
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    pub(super) struct Response {
+        pub result: Result,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct Result {
+        pub episode_list: Vec<Episode>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct Episode {
+        pub episode_no: u16,
+        pub episode_title: String,
+        pub exposure_date_millis: i64,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::platform::webtoons::Client;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn should_build_episodes_from_mobile_api() {
+        let webtoon = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/en/fantasy/tower-of-god/list?title_no=95",
+            &Client::new(),
+        )
+        .unwrap();
+
+        let response: api::Response = serde_json::from_str(
+            r#"{"result":{"episodeList":[{"episodeNo":2,"episodeTitle":"[Season 1] Ep. 2","exposureDateMillis":1277931600000,"thumbnail":""}],"nextCursor":0}}"#,
+        )
+        .unwrap();
+
+        let episode = episode(
+            &webtoon,
+            response.result.episode_list.into_iter().next().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(2, episode.number());
+        assert_eq!(Some(1_277_931_600_000), episode.published());
+        assert_eq!("[Season 1] Ep. 2", episode.title().await.unwrap());
+    }
+}
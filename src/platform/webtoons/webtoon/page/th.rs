@@ -17,7 +17,8 @@ pub(super) fn page(html: &Html, webtoon: &Webtoon) -> Result<Page, WebtoonError>
     let page = match webtoon.scope {
         Scope::Original(_) => Page {
             title: super::en::title(html)?,
-            creators: super::en::creators(html, &webtoon.client)?,
+            original_title: super::en::original_title(html),
+            creators: super::en::creators(html, &webtoon.client, webtoon.language)?,
             genres: super::en::genres(html)?,
             summary: super::en::summary(html)?,
             views: super::en::views(html)?,
@@ -30,7 +31,8 @@ pub(super) fn page(html: &Html, webtoon: &Webtoon) -> Result<Page, WebtoonError>
         },
         Scope::Canvas => Page {
             title: super::en::title(html)?,
-            creators: super::en::creators(html, &webtoon.client)?,
+            original_title: super::en::original_title(html),
+            creators: super::en::creators(html, &webtoon.client, webtoon.language)?,
             genres: super::en::genres(html)?,
             summary: super::en::summary(html)?,
             views: super::en::views(html)?,
@@ -98,8 +100,10 @@ pub(super) fn episode(
         season: Arc::new(Mutex::new(super::super::episode::season(&title))),
         title: Arc::new(Mutex::new(Some(title))),
         number,
+        display_number: super::en::episode_display_number(element),
         published: Some(published),
         page: Arc::new(Mutex::new(None)),
+        comments_and_replies: Arc::new(Mutex::new(None)),
         views: None,
         // NOTE: Impossible to say from this page. In general any random Original episode would have been
         // behind an ad, but the initial release episodes which never were would be impossible to tell.
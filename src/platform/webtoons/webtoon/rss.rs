@@ -1,7 +1,9 @@
 //! Module representing a webtoons rss feed.
 
-use chrono::{DateTime, Utc};
-use std::{str::FromStr, sync::Arc};
+use anyhow::{anyhow, Context};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use scraper::{Html, Selector};
+use std::{collections::HashMap, str::FromStr, sync::Arc};
 use tokio::sync::Mutex;
 use url::Url;
 
@@ -12,19 +14,20 @@ use super::{
     Webtoon, WebtoonError,
 };
 
-// TODO: implement rss for other languages: need to parse the dates
-
 /// Represents the RSS data from the webtoons.com rss feed for the webtoon
 ///
 /// This is not a spec-compliant representation, but rather one that would make sense from a webtoon.com perspective.
 #[derive(Debug)]
 pub struct Rss {
     pub(super) url: String,
+    pub(super) language: Language,
     pub(super) title: String,
     pub(super) summary: String,
     pub(super) thumbnail: String,
     pub(super) creators: Vec<Creator>,
     pub(super) episodes: Vec<Episode>,
+    /// Thumbnails given for the episodes in the feed, keyed by episode number.
+    pub(super) thumbnails: HashMap<u16, String>,
 }
 
 impl Rss {
@@ -63,6 +66,158 @@ impl Rss {
     pub fn episodes(&self) -> &[Episode] {
         &self.episodes
     }
+
+    /// Renders the feed as RSS 2.0 XML, ready to hand to a feed reader.
+    ///
+    /// Each episode becomes an item linking to [`Episode::url`], with its title and publish date. An episode's
+    /// thumbnail is added as an enclosure when the feed gave one, or when it was already fetched with
+    /// [`Episode::thumbnail`], as this makes no requests.
+    #[must_use]
+    pub fn to_rss_xml(&self) -> String {
+        let items = self
+            .episodes
+            .iter()
+            .map(|episode| {
+                let url = episode.url();
+
+                rss::Item {
+                    title: cached_title(episode),
+                    link: Some(url.clone()),
+                    guid: Some(rss::Guid {
+                        value: url,
+                        permalink: true,
+                    }),
+                    pub_date: episode.published.map(|published| published.to_rfc2822()),
+                    enclosure: self
+                        .episode_thumbnail(episode)
+                        .map(|thumbnail| rss::Enclosure {
+                            mime_type: mime_type(&thumbnail).to_string(),
+                            // RSS requires a length, but the size isn't known without downloading the image. Readers
+                            // take `0` as unknown.
+                            length: "0".to_string(),
+                            url: thumbnail,
+                        }),
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        let channel = rss::Channel {
+            title: self.title.clone(),
+            link: self.url.clone(),
+            description: self.summary.clone(),
+            language: Some(self.language.to_string()),
+            image: Some(rss::Image {
+                url: self.thumbnail.clone(),
+                title: self.title.clone(),
+                link: self.url.clone(),
+                ..Default::default()
+            }),
+            items,
+            ..Default::default()
+        };
+
+        channel.to_string()
+    }
+
+    /// Renders the feed as Atom XML, ready to hand to a feed reader.
+    ///
+    /// Holds the same data as [`Rss::to_rss_xml`], with thumbnails as `enclosure` links. The feed's `updated` is the
+    /// publish date of the newest episode.
+    #[must_use]
+    pub fn to_atom_xml(&self) -> String {
+        let entries = self
+            .episodes
+            .iter()
+            .map(|episode| {
+                let url = episode.url();
+                let published = episode.published.map(|published| published.fixed_offset());
+
+                let mut links = vec![atom_syndication::Link {
+                    href: url.clone(),
+                    rel: "alternate".to_string(),
+                    ..Default::default()
+                }];
+
+                if let Some(thumbnail) = self.episode_thumbnail(episode) {
+                    links.push(atom_syndication::Link {
+                        mime_type: Some(mime_type(&thumbnail).to_string()),
+                        href: thumbnail,
+                        rel: "enclosure".to_string(),
+                        ..Default::default()
+                    });
+                }
+
+                atom_syndication::Entry {
+                    title: atom_syndication::Text::plain(cached_title(episode).unwrap_or_default()),
+                    id: url,
+                    updated: published.unwrap_or_default(),
+                    published,
+                    links,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        let updated = self
+            .episodes
+            .iter()
+            .filter_map(|episode| episode.published)
+            .max()
+            .unwrap_or_default()
+            .fixed_offset();
+
+        let feed = atom_syndication::Feed {
+            title: atom_syndication::Text::plain(self.title.clone()),
+            id: self.url.clone(),
+            updated,
+            authors: self
+                .creators
+                .iter()
+                .map(|creator| atom_syndication::Person {
+                    name: creator.username().to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            links: vec![atom_syndication::Link {
+                href: self.url.clone(),
+                rel: "alternate".to_string(),
+                ..Default::default()
+            }],
+            logo: Some(self.thumbnail.clone()),
+            subtitle: Some(atom_syndication::Text::plain(self.summary.clone())),
+            lang: Some(self.language.to_string()),
+            entries,
+            ..Default::default()
+        };
+
+        feed.to_string()
+    }
+
+    /// The thumbnail the feed gave for `episode`, falling back to one already fetched with [`Episode::thumbnail`].
+    fn episode_thumbnail(&self, episode: &Episode) -> Option<String> {
+        self.thumbnails
+            .get(&episode.number)
+            .cloned()
+            .or_else(|| episode.cached_thumbnail())
+    }
+}
+
+/// The title of an episode from the feed is always filled in when parsing, so this doesn't need to make a request.
+fn cached_title(episode: &Episode) -> Option<String> {
+    episode.title.try_lock().ok()?.clone()
+}
+
+fn mime_type(url: &str) -> &'static str {
+    let path = url.split('?').next().unwrap_or(url).to_lowercase();
+
+    if path.ends_with(".png") {
+        "image/png"
+    } else if path.ends_with(".gif") {
+        "image/gif"
+    } else {
+        "image/jpeg"
+    }
 }
 
 pub(super) async fn feed(webtoon: &Webtoon) -> Result<Rss, WebtoonError> {
@@ -73,17 +228,22 @@ pub(super) async fn feed(webtoon: &Webtoon) -> Result<Rss, WebtoonError> {
         .text()
         .await?;
 
-    let channel = rss::Channel::from_str(&response) //
+    parse(webtoon, &response, webtoon.creators().await?)
+}
+
+fn parse(webtoon: &Webtoon, xml: &str, creators: Vec<Creator>) -> Result<Rss, WebtoonError> {
+    let channel = rss::Channel::from_str(xml) //
         .map_err(|err| WebtoonError::Unexpected(err.into()))?;
 
     let mut episodes = Vec::new();
+    let mut thumbnails = HashMap::new();
 
     for item in &channel.items {
         let published = published(
             item.pub_date()
                 .expect("publish date should always be in the rss feed"),
             webtoon.language(),
-        );
+        )?;
 
         let number = episode(
             item.link
@@ -97,13 +257,19 @@ pub(super) async fn feed(webtoon: &Webtoon) -> Result<Rss, WebtoonError> {
             .expect("RSS should always have a tile")
             .clone();
 
+        if let Some(thumbnail) = item_thumbnail(item) {
+            thumbnails.insert(number, thumbnail);
+        }
+
         episodes.push(Episode {
             webtoon: webtoon.clone(),
             number,
+            display_number: None,
             season: Arc::new(Mutex::new(None)),
             title: Arc::new(Mutex::new(Some(title))),
             published: Some(published),
             page: Arc::new(Mutex::new(None)),
+            comments_and_replies: Arc::new(Mutex::new(None)),
             views: None,
             ad_status: None,
             // RSS can only be generated for public and free(not behiong ad or fast-pass) episodes.
@@ -114,53 +280,171 @@ pub(super) async fn feed(webtoon: &Webtoon) -> Result<Rss, WebtoonError> {
     Ok(Rss {
         title: channel.title.clone(),
         url: channel.link.clone(),
+        language: webtoon.language,
         thumbnail: channel
             .image()
             .expect("webtoon rss should have image")
             .url
             .clone(),
-        creators: webtoon.creators().await?,
+        creators,
         summary: channel.description,
         episodes,
+        thumbnails,
     })
 }
 
-fn published(date: &str, language: Language) -> DateTime<Utc> {
-    match language {
-        Language::En => {
-            // EX: Tuesday, 10 Sep 2024 16:40:23 GMT
-            let date = date.replace("GMT", "+0000");
+/// Gets the thumbnail of an item, either from its `enclosure` or from an `img` in its `description`.
+fn item_thumbnail(item: &rss::Item) -> Option<String> {
+    if let Some(enclosure) = item.enclosure() {
+        return Some(enclosure.url().to_string());
+    }
 
-            let date = DateTime::parse_from_str(&date, "%A, %d %b %Y %T %z")
-                .expect("RSS feed `pubDate` should always be the same format");
+    let selector = Selector::parse("img[src]").expect("`img[src]` should be a valid selector");
 
-            DateTime::<Utc>::from(date)
-        }
-        Language::Zh => {
-            // EX: 星期二, 17 9月 2024 13:01:22 GMT
-            let mut date = date
-                .split_once(' ')
-                .map(|(_, date)| date.trim_end_matches("GMT"))
-                .expect("chinese date should have space in it")
-                .to_string();
-
-            date.push_str("+0000");
-
-            DateTime::parse_from_str(&date, "%d年 %m月 %Y %T %z")
-                .expect("chinese rss date should have pattern `17 9月 2024 13:01:22 +0000`")
-                .into()
-        }
-        // วันอังคาร, 17 ก.ย. 2024 13:04:59 GMT
-        Language::Th => todo!(),
-        // Selasa, 17 Sep 2024 15:03:59 GMT
-        Language::Id => todo!(),
-        // miércoles, 18 sept. 2024 01:01:48 GMT
-        Language::Es => todo!(),
-        // mercredi, 18 sept. 2024 14:01:48 GMT
-        Language::Fr => todo!(),
-        // Mittwoch, 18 Sep. 2024 14:01:20 GMT
-        Language::De => todo!(),
+    Html::parse_fragment(item.description()?)
+        .select(&selector)
+        .next()?
+        .value()
+        .attr("src")
+        .map(str::to_string)
+}
+
+/// Parses the localized `pubDate` of the feed.
+///
+/// Every language follows `{weekday}, {day} {month} {year} {time} GMT`, with only the weekday and month names being
+/// localized:
+///
+/// - en: `Tuesday, 10 Sep 2024 16:40:23 GMT`
+/// - zh: `星期二, 17 9月 2024 13:01:22 GMT`
+/// - th: `วันอังคาร, 17 ก.ย. 2024 13:04:59 GMT`
+/// - id: `Selasa, 17 Sep 2024 15:03:59 GMT`
+/// - es: `miércoles, 18 sept. 2024 01:01:48 GMT`
+/// - fr: `mercredi, 18 sept. 2024 14:01:48 GMT`
+/// - de: `Mittwoch, 18 Sep. 2024 14:01:20 GMT`
+fn published(date: &str, language: Language) -> Result<DateTime<Utc>, WebtoonError> {
+    let (_weekday, rest) = date
+        .split_once(", ")
+        .with_context(|| format!("rss `pubDate` `{date}` should start with a weekday"))?;
+
+    let mut parts = rest.split_whitespace();
+
+    let (Some(day), Some(month), Some(year), Some(time)) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(WebtoonError::Unexpected(anyhow!(
+            "rss `pubDate` `{date}` should have a day, month, year, and time"
+        )));
+    };
+
+    let month = month_number(month, language)
+        .with_context(|| format!("`{month}` is not a known {language} month in rss `pubDate`"))?;
+
+    let date = format!("{year}-{month:02}-{day:0>2} {time}");
+
+    let date = NaiveDateTime::parse_from_str(&date, "%Y-%m-%d %T")
+        .with_context(|| format!("rss `pubDate` `{date}` was not a valid date"))?;
+
+    Ok(date.and_utc())
+}
+
+fn month_number(month: &str, language: Language) -> Option<u32> {
+    if language == Language::Zh {
+        return month.trim_end_matches('月').parse().ok();
     }
+
+    let month = month.trim_end_matches('.').to_lowercase();
+
+    let months: [&[&str]; 12] = match language {
+        Language::En | Language::Zh => [
+            &["jan"],
+            &["feb"],
+            &["mar"],
+            &["apr"],
+            &["may"],
+            &["jun"],
+            &["jul"],
+            &["aug"],
+            &["sep"],
+            &["oct"],
+            &["nov"],
+            &["dec"],
+        ],
+        Language::Th => [
+            &["ม.ค"],
+            &["ก.พ"],
+            &["มี.ค"],
+            &["เม.ย"],
+            &["พ.ค"],
+            &["มิ.ย"],
+            &["ก.ค"],
+            &["ส.ค"],
+            &["ก.ย"],
+            &["ต.ค"],
+            &["พ.ย"],
+            &["ธ.ค"],
+        ],
+        Language::Id => [
+            &["jan"],
+            &["feb"],
+            &["mar"],
+            &["apr"],
+            &["mei"],
+            &["jun"],
+            &["jul"],
+            &["agu", "agt", "agus"],
+            &["sep"],
+            &["okt"],
+            &["nov"],
+            &["des"],
+        ],
+        Language::Es => [
+            &["ene"],
+            &["feb"],
+            &["mar"],
+            &["abr"],
+            &["may"],
+            &["jun"],
+            &["jul"],
+            &["ago"],
+            &["sept", "sep"],
+            &["oct"],
+            &["nov"],
+            &["dic"],
+        ],
+        Language::Fr => [
+            &["janv"],
+            &["févr"],
+            &["mars"],
+            &["avr"],
+            &["mai"],
+            &["juin"],
+            &["juil"],
+            &["août"],
+            &["sept"],
+            &["oct"],
+            &["nov"],
+            &["déc"],
+        ],
+        Language::De => [
+            &["jan"],
+            &["feb"],
+            &["märz", "mär"],
+            &["apr"],
+            &["mai"],
+            &["juni", "jun"],
+            &["juli", "jul"],
+            &["aug"],
+            &["sep", "sept"],
+            &["okt"],
+            &["nov"],
+            &["dez"],
+        ],
+    };
+
+    months
+        .iter()
+        .position(|names| names.contains(&month.as_str()))
+        .map(|position| position as u32 + 1)
 }
 
 fn episode(url: &str) -> u16 {
@@ -178,3 +462,146 @@ fn episode(url: &str) -> u16 {
 
     u16::from_str(&value).expect("`episode_no` should always have a number parsable to a u16")
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::platform::webtoons::Client;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_parse_localized_dates() {
+        let expected = "2024-09-18T14:01:20Z";
+
+        for (language, date) in [
+            (Language::En, "Wednesday, 18 Sep 2024 14:01:20 GMT"),
+            (Language::Zh, "星期三, 18 9月 2024 14:01:20 GMT"),
+            (Language::Th, "วันพุธ, 18 ก.ย. 2024 14:01:20 GMT"),
+            (Language::Id, "Rabu, 18 Sep 2024 14:01:20 GMT"),
+            (Language::Es, "miércoles, 18 sept. 2024 14:01:20 GMT"),
+            (Language::Fr, "mercredi, 18 sept. 2024 14:01:20 GMT"),
+            (Language::De, "Mittwoch, 18 Sep. 2024 14:01:20 GMT"),
+        ] {
+            assert_eq!(
+                expected,
+                published(date, language)
+                    .unwrap()
+                    .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                "{language}"
+            );
+        }
+
+        assert!(published("miércoles, 18 foo. 2024 14:01:20 GMT", Language::Es).is_err());
+    }
+
+    const SPANISH_FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0">
+  <channel>
+    <title>Tower of God</title>
+    <link>https://www.webtoons.com/es/fantasy/tower-of-god/list?title_no=1955</link>
+    <description>¿Qué deseas?</description>
+    <image>
+      <url>https://swebtoon-phinf.pstatic.net/thumbnail.jpg</url>
+      <title>Tower of God</title>
+      <link>https://www.webtoons.com/es/fantasy/tower-of-god/list?title_no=1955</link>
+    </image>
+    <item>
+      <title>Episodio 2</title>
+      <link>https://www.webtoons.com/es/fantasy/tower-of-god/episodio-2/viewer?title_no=1955&amp;episode_no=2</link>
+      <description><![CDATA[<img src="https://swebtoon-phinf.pstatic.net/episode-2.jpg">]]></description>
+      <pubDate>jueves, 2 ene. 2025 09:30:00 GMT</pubDate>
+    </item>
+    <item>
+      <title>Episodio 1</title>
+      <link>https://www.webtoons.com/es/fantasy/tower-of-god/episodio-1/viewer?title_no=1955&amp;episode_no=1</link>
+      <enclosure url="https://swebtoon-phinf.pstatic.net/episode-1.png" length="0" type="image/png"/>
+      <pubDate>miércoles, 18 sept. 2024 01:01:48 GMT</pubDate>
+    </item>
+  </channel>
+</rss>"#;
+
+    fn spanish_feed() -> Rss {
+        let webtoon = Webtoon::from_url_with_client(
+            "https://www.webtoons.com/es/fantasy/tower-of-god/list?title_no=1955",
+            &Client::new(),
+        )
+        .unwrap();
+
+        parse(&webtoon, SPANISH_FEED, Vec::new()).unwrap()
+    }
+
+    #[test]
+    fn should_parse_spanish_feed() {
+        let rss = spanish_feed();
+
+        assert_eq!("Tower of God", rss.title());
+        assert_eq!("¿Qué deseas?", rss.summary());
+        assert_eq!(
+            vec![(2, Some(1_735_810_200_000)), (1, Some(1_726_621_308_000))],
+            rss.episodes()
+                .iter()
+                .map(|episode| (episode.number(), episode.published()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_write_feeds_that_parse_back() {
+        let rss = spanish_feed();
+
+        let channel = rss::Channel::from_str(&rss.to_rss_xml()).unwrap();
+        assert_eq!("Tower of God", channel.title());
+        assert_eq!(Some("es"), channel.language());
+        assert_eq!(
+            vec![
+                Some("https://www.webtoons.com/es/fantasy/tower-of-god/episode-2/viewer?title_no=1955&episode_no=2"),
+                Some("https://www.webtoons.com/es/fantasy/tower-of-god/episode-1/viewer?title_no=1955&episode_no=1"),
+            ],
+            channel.items().iter().map(rss::Item::link).collect::<Vec<_>>()
+        );
+        assert_eq!(Some("Episodio 2"), channel.items()[0].title());
+        assert_eq!(
+            Some("Thu, 2 Jan 2025 09:30:00 +0000"),
+            channel.items()[0].pub_date()
+        );
+        assert_eq!(
+            vec![
+                Some((
+                    "https://swebtoon-phinf.pstatic.net/episode-2.jpg",
+                    "image/jpeg"
+                )),
+                Some((
+                    "https://swebtoon-phinf.pstatic.net/episode-1.png",
+                    "image/png"
+                )),
+            ],
+            channel
+                .items()
+                .iter()
+                .map(|item| item
+                    .enclosure()
+                    .map(|enclosure| (enclosure.url(), enclosure.mime_type())))
+                .collect::<Vec<_>>()
+        );
+
+        let feed = atom_syndication::Feed::from_str(&rss.to_atom_xml()).unwrap();
+        assert_eq!("Tower of God", feed.title().as_str());
+        assert_eq!("2025-01-02T09:30:00+00:00", feed.updated().to_rfc3339());
+        assert_eq!(2, feed.entries().len());
+        assert_eq!("Episodio 1", feed.entries()[1].title().as_str());
+        assert_eq!(
+            "https://www.webtoons.com/es/fantasy/tower-of-god/episode-1/viewer?title_no=1955&episode_no=1",
+            feed.entries()[1].links()[0].href()
+        );
+        assert_eq!(
+            (
+                "enclosure",
+                "https://swebtoon-phinf.pstatic.net/episode-1.png"
+            ),
+            (
+                feed.entries()[1].links()[1].rel(),
+                feed.entries()[1].links()[1].href()
+            )
+        );
+    }
+}
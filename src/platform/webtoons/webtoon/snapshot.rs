@@ -0,0 +1,49 @@
+//! Module containing a point-in-time record of a webtoon's stats.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A record of a [`Webtoon`](super::Webtoon)'s public stats, fetched together around [`StatsSnapshot::at`].
+///
+/// Returned by [`Webtoon::stats_snapshot`](super::Webtoon::stats_snapshot). Each value comes from its own request, so
+/// they can be a few moments apart.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StatsSnapshot {
+    pub(super) views: u64,
+    pub(super) subscribers: u32,
+    pub(super) rating: f64,
+    pub(super) likes: u32,
+    pub(super) at: DateTime<Utc>,
+}
+
+impl StatsSnapshot {
+    /// Returns the total views of the webtoon.
+    #[must_use]
+    pub fn views(&self) -> u64 {
+        self.views
+    }
+
+    /// Returns the subscriber count of the webtoon.
+    #[must_use]
+    pub fn subscribers(&self) -> u32 {
+        self.subscribers
+    }
+
+    /// Returns the rating of the webtoon.
+    #[must_use]
+    pub fn rating(&self) -> f64 {
+        self.rating
+    }
+
+    /// Returns the total likes across all episodes of the webtoon.
+    #[must_use]
+    pub fn likes(&self) -> u32 {
+        self.likes
+    }
+
+    /// Returns when the snapshot was started.
+    #[must_use]
+    pub fn at(&self) -> DateTime<Utc> {
+        self.at
+    }
+}
@@ -1 +1,2 @@
 pub mod base36;
+pub mod lru;
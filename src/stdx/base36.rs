@@ -11,6 +11,11 @@ impl Base36 {
     pub fn new(n: u32) -> Self {
         Self(n)
     }
+
+    /// The decoded number.
+    pub const fn get(self) -> u32 {
+        self.0
+    }
 }
 
 impl Add for Base36 {
@@ -0,0 +1,102 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+};
+
+/// A bounded map that evicts the least recently used entry once full.
+#[derive(Debug)]
+pub struct Lru<K, V> {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<K, (V, u64)>,
+    // Last used tick to key, oldest first.
+    order: BTreeMap<u64, K>,
+}
+
+impl<K, V> Lru<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tick: 0,
+            entries: HashMap::with_capacity(capacity),
+            order: BTreeMap::new(),
+        }
+    }
+
+    /// Gets a clone of the value for `key`, marking it as the most recently used.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        self.tick += 1;
+
+        let (value, used) = self.entries.get_mut(key)?;
+
+        self.order.remove(used);
+        *used = self.tick;
+        self.order.insert(self.tick, key.clone());
+
+        Some(value.clone())
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.tick += 1;
+
+        if let Some((_, used)) = self.entries.insert(key.clone(), (value, self.tick)) {
+            self.order.remove(&used);
+        }
+
+        self.order.insert(self.tick, key);
+
+        while self.entries.len() > self.capacity {
+            let Some((_, oldest)) = self.order.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+
+    pub fn remove(&mut self, key: &K) {
+        if let Some((_, used)) = self.entries.remove(key) {
+            self.order.remove(&used);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn should_evict_least_recently_used() {
+        let mut lru = Lru::new(2);
+
+        lru.insert(1, "one");
+        lru.insert(2, "two");
+
+        // `1` is now more recently used than `2`.
+        assert_eq!(lru.get(&1), Some("one"));
+
+        lru.insert(3, "three");
+
+        assert_eq!(lru.get(&2), None);
+        assert_eq!(lru.get(&1), Some("one"));
+        assert_eq!(lru.get(&3), Some("three"));
+
+        lru.remove(&1);
+        assert_eq!(lru.get(&1), None);
+    }
+
+    #[test]
+    fn should_not_store_with_zero_capacity() {
+        let mut lru = Lru::new(0);
+        lru.insert(1, "one");
+        assert_eq!(lru.get(&1), None);
+    }
+}
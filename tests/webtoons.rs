@@ -65,7 +65,8 @@ async fn webtoon() -> Result<(), Error> {
     let _banner = webtoon.banner().await.unwrap();
     let _lang = webtoon.language();
     let _creators = webtoon.creators().await.unwrap();
-    let _genres = webtoon.genres().await.unwrap();
+    let genres = webtoon.genres().await.unwrap();
+    assert!(!genres.is_empty(), "canvas webtoons should have a genre");
     let _release = webtoon.release().await.unwrap();
     let _views = webtoon.views().await.unwrap();
     let _likes = webtoon.likes().await.unwrap();